@@ -2,9 +2,11 @@
 //!
 //! The goal is globs that are portable between languages and operating systems.
 
-use globset::{Glob, GlobSet, GlobSetBuilder};
+use globset::Glob;
 use itertools::Itertools;
 use regex::bytes::Regex as BytesRegex;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::path::Path;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -32,6 +34,77 @@ pub enum Pep639GlobError {
     TooManyStars { glob: String, pos: usize },
 }
 
+/// Rich [`miette::Diagnostic`] rendering for [`Pep639GlobError`], so tools embedding uv can print
+/// a caret pointing at the exact bad character instead of just the flat `Display` message. This
+/// is additive: the plain `Display` output above is untouched, so snapshot tests that only check
+/// `.to_string()` keep passing byte-for-byte.
+#[cfg(feature = "diagnostics")]
+mod diagnostics {
+    use super::Pep639GlobError;
+    use miette::{Diagnostic, LabeledSpan, SourceSpan};
+
+    impl Diagnostic for Pep639GlobError {
+        fn help<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+            let help: &'static str = match self {
+                Self::GlobError(_) => return None,
+                Self::ParentDirectory { .. } => {
+                    "PEP 639 forbids the parent directory operator (`..`) in `license-files` globs"
+                }
+                Self::InvalidCharacter { .. } => {
+                    "only alphanumerics, `_`, `-`, `.`, `/`, `*`, `**`, `?` and `[...]` ranges are \
+                     allowed in a PEP 639 glob"
+                }
+                Self::InvalidCharacterRange { .. } => {
+                    "a `[...]` range may only contain alphanumerics, `_`, `-` and `.`"
+                }
+                Self::TooManyStars { .. } => {
+                    "`***` and `**` followed by a literal are ambiguous; use `*` or `**/`"
+                }
+            };
+            Some(Box::new(help))
+        }
+
+        fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+            match self {
+                Self::GlobError(_) => None,
+                Self::ParentDirectory { glob, .. }
+                | Self::InvalidCharacter { glob, .. }
+                | Self::InvalidCharacterRange { glob, .. }
+                | Self::TooManyStars { glob, .. } => Some(glob),
+            }
+        }
+
+        fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+            let (span, label) = match self {
+                Self::GlobError(_) => return None,
+                Self::ParentDirectory { pos, .. } => {
+                    (SourceSpan::from((*pos, 2)), "parent directory operator")
+                }
+                Self::InvalidCharacter { pos, .. } => (SourceSpan::from((*pos, 1)), "invalid character"),
+                Self::InvalidCharacterRange { glob, pos, .. } => (
+                    bracket_span(glob, *pos),
+                    "invalid character in character range",
+                ),
+                Self::TooManyStars { pos, .. } => (SourceSpan::from((*pos, 1)), "too many stars"),
+            };
+            Some(Box::new(std::iter::once(LabeledSpan::new_with_span(
+                Some(label.to_string()),
+                span,
+            ))))
+        }
+    }
+
+    /// Expand a position inside a `[...]` character range into a span covering the whole range,
+    /// so the label highlights `[C?]` rather than just the offending `?`.
+    fn bracket_span(glob: &str, pos: usize) -> SourceSpan {
+        let start = glob[..pos].rfind('[').unwrap_or(pos);
+        let end = glob[pos..]
+            .find(']')
+            .map_or(glob.len(), |offset| pos + offset + 1);
+        SourceSpan::from(start..end)
+    }
+}
+
 /// Parse a PEP 639 `license-files` glob.
 ///
 /// The syntax is more restricted than regular globbing in Python or Rust for platform independent
@@ -139,25 +212,201 @@ fn check_pep639_globs(glob: &str) -> Result<(), Pep639GlobError> {
     Ok(())
 }
 
+/// A cheap-to-evaluate classification of a single glob, ported from globset's internal
+/// `MatchStrategy`.
+///
+/// Most `license-files`/build-include globs in the wild are either a bare literal, a `*.ext`
+/// extension filter, or a `dir/**` prefix. Those can be answered with a hash lookup or a
+/// `starts_with`/`ends_with` check instead of running the compiled regex, which matters once the
+/// include list (or the tree being walked) grows into the thousands of entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MatchStrategy {
+    /// The glob has no wildcards at all, e.g. `pyproject.toml`: it matches a single full path.
+    Literal(String),
+    /// The glob is `**/name` with no other wildcards: it matches any path with this basename.
+    BasenameLiteral(String),
+    /// The glob is `*.ext`: it matches any path whose basename ends in `.ext`.
+    Extension(String),
+    /// The glob is `dir/**`: it matches any path starting with `dir/`.
+    Prefix(String),
+    /// The glob is `**/a/b`: it matches any path ending in `/a/b`, anchored at a `/` boundary.
+    Suffix(String),
+    /// Anything else; falls back to the compiled regex.
+    Regex,
+}
+
+impl MatchStrategy {
+    /// Classify a validated PEP 639 glob into a fast-path strategy, falling back to
+    /// [`MatchStrategy::Regex`] for anything that doesn't fit the restricted set of shapes below.
+    ///
+    /// This operates on the glob source text directly: the PEP 639 grammar is restrictive enough
+    /// (no escaping, `/`-only separators, `**` only as a whole path component) that a handful of
+    /// `strip_prefix`/`strip_suffix` checks are enough to recognize the shapes globset itself
+    /// special-cases.
+    fn new(glob: &str) -> Self {
+        if !glob.contains(['*', '?', '[']) {
+            return Self::Literal(glob.to_string());
+        }
+
+        // `*.ext`: a single leading star and dot, then a literal extension with no further
+        // wildcards or directory separators. The dot itself is not part of the stored key: `ext`
+        // is matched against a basename's extension as returned by `rsplit_once('.')`, which
+        // never includes the dot either. `ext` must not itself contain a `.`: `rsplit_once('.')`
+        // only ever recovers the last dot-component (`"gz"` for `archive.tar.gz`, not `"tar.gz"`),
+        // so a multi-dot extension like `*.tar.gz` can't be answered by this fast path and has to
+        // fall through to the regex instead.
+        if let Some(ext) = glob.strip_prefix("*.") {
+            if !ext.is_empty() && !ext.contains(['*', '?', '[', '/', '.']) {
+                return Self::Extension(ext.to_string());
+            }
+        }
+
+        // `dir/**`: a literal prefix, then a recursive suffix that matches anything below it.
+        if let Some(prefix) = glob.strip_suffix("/**") {
+            if !prefix.is_empty() && !prefix.contains(['*', '?', '[']) {
+                return Self::Prefix(format!("{prefix}/"));
+            }
+        }
+
+        // `**/literal`: a recursive prefix, then a literal with no further wildcards. If the
+        // literal is a bare basename we can key off it directly; otherwise it's a multi-component
+        // suffix that still has to be checked at a `/` boundary.
+        if let Some(suffix) = glob.strip_prefix("**/") {
+            if !suffix.is_empty() && !suffix.contains(['*', '?', '[']) {
+                return if suffix.contains('/') {
+                    Self::Suffix(format!("/{suffix}"))
+                } else {
+                    Self::BasenameLiteral(suffix.to_string())
+                };
+            }
+        }
+
+        Self::Regex
+    }
+}
+
+/// A compiled set of PEP 639 globs, dispatching each candidate path to the cheapest applicable
+/// [`MatchStrategy`] instead of always evaluating the full alternation regex.
+///
+/// See [`MatchStrategy`] for the fast paths; everything that doesn't fit one of them still goes
+/// through `regex`, so matching semantics are identical to the plain globset-backed
+/// implementation this replaces.
 pub(crate) struct Pep639GlobSet {
-    globset: GlobSet,
-    filter: Option<BytesRegex>,
+    /// Globs matched through [`MatchStrategy::Literal`], keyed by the full path.
+    literals: FxHashSet<String>,
+    /// Globs matched through [`MatchStrategy::BasenameLiteral`], keyed by basename.
+    basenames: FxHashSet<String>,
+    /// Globs matched through [`MatchStrategy::Extension`], keyed by extension (without the dot).
+    extensions: FxHashSet<String>,
+    /// Globs matched through [`MatchStrategy::Prefix`], each including the trailing `/`.
+    prefixes: Vec<String>,
+    /// Globs matched through [`MatchStrategy::Suffix`], each including the leading `/`.
+    suffixes: Vec<String>,
+    /// The combined alternation regex for every glob that didn't fit a fast-path strategy above.
+    /// `None` when every glob had a fast path, so no candidate ever has to hit the regex engine.
+    regex: Option<BytesRegex>,
 }
 
 impl Pep639GlobSet {
     pub(crate) fn new(globs: &[String]) -> Result<Self, Pep639GlobError> {
-        let mut include_builder = GlobSetBuilder::new();
+        let mut literals = FxHashSet::default();
+        let mut basenames = FxHashSet::default();
+        let mut extensions = FxHashSet::default();
+        let mut prefixes = Vec::new();
+        let mut suffixes = Vec::new();
         let mut regexes = Vec::new();
         for glob in globs {
-            let glob = parse_pep639_glob(glob)?;
-            regexes.push(glob.regex());
-            include_builder.add(glob);
+            let compiled = parse_pep639_glob(glob)?;
+            match MatchStrategy::new(glob) {
+                MatchStrategy::Literal(literal) => {
+                    literals.insert(literal);
+                }
+                MatchStrategy::BasenameLiteral(basename) => {
+                    basenames.insert(basename);
+                }
+                MatchStrategy::Extension(ext) => {
+                    extensions.insert(ext);
+                }
+                MatchStrategy::Prefix(prefix) => {
+                    prefixes.push(prefix);
+                }
+                MatchStrategy::Suffix(suffix) => {
+                    suffixes.push(suffix);
+                }
+                MatchStrategy::Regex => {
+                    regexes.push(compiled.regex().to_string());
+                }
+            }
+        }
+
+        let regex = if regexes.is_empty() {
+            None
+        } else {
+            let pattern = "^".to_string() + &regexes.iter().map(|re| format!("({re})")).join("|");
+            Some(BytesRegex::new(&pattern).expect("regex generated by globset is valid"))
+        };
+
+        Ok(Self {
+            literals,
+            basenames,
+            extensions,
+            prefixes,
+            suffixes,
+            regex,
+        })
+    }
+
+    /// Whether `path` is matched by any of the globs in this set.
+    pub(crate) fn match_path(&self, path: &Path) -> bool {
+        let Some(path) = path.to_str() else {
+            // Non-UTF-8 paths can't match a PEP 639 glob, which is restricted to alphanumerics,
+            // `_-.`, `/`, and a handful of ASCII wildcard characters.
+            return false;
+        };
+        self.is_match(path)
+    }
+
+    /// Whether `path` is matched by any of the globs in this set.
+    ///
+    /// This is the actual dispatcher: [`Self::match_path`] is a thin `Path` wrapper around it.
+    pub(crate) fn is_match(&self, path: &str) -> bool {
+        if self.literals.contains(path) {
+            return true;
+        }
+
+        let basename = path.rsplit('/').next().unwrap_or(path);
+        if self.basenames.contains(basename) {
+            return true;
+        }
+
+        if !self.extensions.is_empty() {
+            if let Some(ext) = basename.rsplit_once('.').map(|(_, ext)| ext) {
+                if self.extensions.contains(ext) {
+                    return true;
+                }
+            }
+        }
+
+        if self
+            .prefixes
+            .iter()
+            .any(|prefix| path.starts_with(prefix.as_str()))
+        {
+            return true;
+        }
+
+        // The stored suffix carries its leading `/` so `ends_with` enforces the component
+        // boundary PEP 639 requires below the root, but `**/` also matches zero directories, so
+        // a root-level path equal to the suffix (without the leading `/`) must match too.
+        if self.suffixes.iter().any(|suffix| {
+            path.ends_with(suffix.as_str()) || path == suffix.trim_start_matches('/')
+        }) {
+            return true;
         }
-        let globset_regex = "^".to_string() + &regexes.iter().map(|re| format!("({re})")).join("|");
-        let filter = BytesRegex::new(&globset_regex).expect("regex generated by globset is valid");
 
-        let globset = include_builder.build()?;
-        globset.Ok(Self { globset })
+        self.regex
+            .as_ref()
+            .is_some_and(|regex| regex.is_match(path.as_bytes()))
     }
 }
 
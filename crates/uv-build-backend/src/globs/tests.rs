@@ -63,3 +63,29 @@ fn test_valid() {
         parse_pep639_glob(case).unwrap();
     }
 }
+
+#[test]
+fn test_extension_glob_matches() {
+    let globs = vec!["*.txt".to_string()];
+    let set = Pep639GlobSet::new(&globs).unwrap();
+    assert!(set.is_match("LICENSE.txt"));
+    assert!(set.is_match("licenses/LICENSE.txt"));
+    assert!(!set.is_match("LICENSE.md"));
+}
+
+#[test]
+fn test_multi_dot_extension_glob_matches() {
+    let globs = vec!["*.tar.gz".to_string()];
+    let set = Pep639GlobSet::new(&globs).unwrap();
+    assert!(set.is_match("archive.tar.gz"));
+    assert!(!set.is_match("archive.gz"));
+}
+
+#[test]
+fn test_multi_component_suffix_matches_at_root() {
+    let globs = vec!["**/a/b".to_string()];
+    let set = Pep639GlobSet::new(&globs).unwrap();
+    assert!(set.is_match("a/b"));
+    assert!(set.is_match("nested/a/b"));
+    assert!(!set.is_match("a/c"));
+}
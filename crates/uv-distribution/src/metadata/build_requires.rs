@@ -1,11 +1,15 @@
 use std::collections::BTreeMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
 
 use uv_configuration::SourceStrategy;
 use uv_distribution_types::{IndexLocations, Requirement};
 use uv_normalize::PackageName;
 use uv_pypi_types::VerbatimParsedUrl;
-use uv_workspace::pyproject::{ExtraBuildDependencies, ToolUvSources};
+use uv_workspace::pyproject::{ExtraBuildDependencies, ExtraBuildDependencyKey, ToolUvSources};
 use uv_workspace::{
     DiscoveryOptions, MemberDiscovery, ProjectWorkspace, Workspace, WorkspaceCache,
 };
@@ -19,6 +23,25 @@ pub struct BuildRequires {
     pub requires_dist: Vec<Requirement>,
 }
 
+/// The file name of an externally-generated [`BuildRequires`] manifest, checked for in a
+/// project's install path before falling back to `pyproject.toml` discovery.
+///
+/// This lets monorepos driven by an external build system (e.g. Bazel-style generators) hand uv
+/// a package's build inputs directly, rather than requiring uv to discover them by walking the
+/// workspace.
+const BUILD_REQUIRES_MANIFEST: &str = "uv-build-requires.json";
+
+/// The on-disk representation of an externally-generated [`BuildRequires`] manifest.
+///
+/// Only `name` and `requires` are read. The `requires` are taken in pre-lowered form, so any
+/// `tool.uv` index/source overrides have to be applied to them already; other keys are ignored.
+#[derive(Debug, Deserialize)]
+struct BuildRequiresManifest {
+    name: Option<PackageName>,
+    #[serde(default)]
+    requires: Vec<Requirement>,
+}
+
 impl BuildRequires {
     /// Lower without considering `tool.uv` in `pyproject.toml`, used for index and other archive
     /// dependencies.
@@ -33,8 +56,29 @@ impl BuildRequires {
         }
     }
 
+    /// Read an externally-generated [`BuildRequiresManifest`] from `manifest_path`, bypassing
+    /// `pyproject.toml` discovery entirely.
+    ///
+    /// The manifest lists the package name and `build-system.requires`, with any `tool.uv`
+    /// index/source overrides already applied in pre-lowered form, so this produces the same
+    /// `requires_dist: Vec<Requirement>` as [`Self::from_project_workspace`] without walking the
+    /// workspace.
+    pub fn from_project_json(manifest_path: &Path) -> Result<Self, MetadataError> {
+        let contents = fs_err::read_to_string(manifest_path)
+            .map_err(|err| MetadataError::BuildRequiresManifest(manifest_path.to_path_buf(), err))?;
+        let manifest: BuildRequiresManifest = serde_json::from_str(&contents)
+            .map_err(|err| MetadataError::BuildRequiresManifestJson(manifest_path.to_path_buf(), err))?;
+        Ok(Self {
+            name: manifest.name,
+            requires_dist: manifest.requires,
+        })
+    }
+
     /// Lower by considering `tool.uv` in `pyproject.toml` if present, used for Git and directory
     /// dependencies.
+    ///
+    /// If `install_path` contains a [`BUILD_REQUIRES_MANIFEST`] file, it takes precedence over
+    /// workspace discovery; see [`Self::from_project_json`].
     pub async fn from_project_maybe_workspace(
         metadata: uv_pypi_types::BuildRequires,
         install_path: &Path,
@@ -42,6 +86,11 @@ impl BuildRequires {
         sources: SourceStrategy,
         cache: &WorkspaceCache,
     ) -> Result<Self, MetadataError> {
+        let manifest_path = install_path.join(BUILD_REQUIRES_MANIFEST);
+        if manifest_path.is_file() {
+            return Self::from_project_json(&manifest_path);
+        }
+
         let discovery = match sources {
             SourceStrategy::Enabled => DiscoveryOptions::default(),
             SourceStrategy::Disabled => DiscoveryOptions {
@@ -205,6 +254,111 @@ impl BuildRequires {
     }
 }
 
+/// Whether a [`BuildRequiresCache`] shares a single lowering across an entire workspace, or
+/// keeps an independent lowering per member.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildRequiresCacheMode {
+    /// Lower `build-system.requires` once per workspace root and `SourceStrategy`, and reuse it
+    /// for every member, on the assumption that all members inherit the same
+    /// `tool.uv.index`/`tool.uv.sources`. Members are still distinguished by their
+    /// `build-system.requires` input, so this is only ever an optimization, never a correctness
+    /// risk, for members whose `build-system.requires` happens to diverge too.
+    Once,
+    /// Lower `build-system.requires` independently for each package, for workspaces whose
+    /// members diverge in their `tool.uv.index`/`tool.uv.sources`.
+    PerMember,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct BuildRequiresCacheKey {
+    workspace_root: PathBuf,
+    package_name: Option<PackageName>,
+    sources_enabled: bool,
+    /// A fingerprint of the pre-lowered `build-system.requires` being resolved.
+    ///
+    /// `Once` mode assumes every member shares the same `tool.uv.index`/`tool.uv.sources`, but
+    /// members still declare their own `build-system.requires`; without this, the first member
+    /// resolved under a given workspace root and `SourceStrategy` would silently donate its
+    /// lowered result to every other member, even when their raw requirements differ.
+    requires_fingerprint: String,
+}
+
+impl BuildRequiresCacheKey {
+    fn new(
+        mode: BuildRequiresCacheMode,
+        workspace_root: &Path,
+        metadata: &uv_pypi_types::BuildRequires,
+        source_strategy: SourceStrategy,
+    ) -> Self {
+        Self {
+            workspace_root: workspace_root.to_path_buf(),
+            package_name: match mode {
+                BuildRequiresCacheMode::Once => None,
+                BuildRequiresCacheMode::PerMember => metadata.name.clone(),
+            },
+            sources_enabled: matches!(source_strategy, SourceStrategy::Enabled),
+            requires_fingerprint: format!("{:?}", metadata.requires_dist),
+        }
+    }
+}
+
+/// A cache of lowered `build-system.requires`, keyed on workspace root, package name, the
+/// `build-system.requires` being lowered, and [`SourceStrategy`].
+///
+/// Resolving `build-system.requires` re-extracts `tool.uv.index`/`tool.uv.sources` and re-lowers
+/// every requirement on every call; for a large workspace, [`BuildRequires::from_workspace`]
+/// repeats that work identically for each member that shares a root. This cache memoizes the
+/// result, analogous to how workspace build-script outputs are computed once and reused.
+#[derive(Debug)]
+pub struct BuildRequiresCache {
+    mode: BuildRequiresCacheMode,
+    entries: Mutex<FxHashMap<BuildRequiresCacheKey, BuildRequires>>,
+}
+
+impl BuildRequiresCache {
+    /// Create a new, empty cache using the given resolution mode.
+    pub fn new(mode: BuildRequiresCacheMode) -> Self {
+        Self {
+            mode,
+            entries: Mutex::new(FxHashMap::default()),
+        }
+    }
+
+    /// Lower `build-system.requires`, reusing a cached result if one is available for this
+    /// workspace root, package name (in [`BuildRequiresCacheMode::PerMember`] mode),
+    /// `build-system.requires`, and [`SourceStrategy`].
+    pub fn resolve(
+        &self,
+        metadata: uv_pypi_types::BuildRequires,
+        workspace: &Workspace,
+        locations: &IndexLocations,
+        source_strategy: SourceStrategy,
+    ) -> Result<BuildRequires, MetadataError> {
+        let key = BuildRequiresCacheKey::new(
+            self.mode,
+            workspace.install_path(),
+            &metadata,
+            source_strategy,
+        );
+
+        if let Some(cached) = self.entries.lock().unwrap().get(&key) {
+            // In `Once` mode the key omits the package name, so the entry may have been lowered
+            // for another member; keep the caller's own name.
+            return Ok(BuildRequires {
+                name: metadata.name,
+                requires_dist: cached.requires_dist.clone(),
+            });
+        }
+
+        let resolved = BuildRequires::from_workspace(metadata, workspace, locations, source_strategy)?;
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(key, resolved.clone());
+        Ok(resolved)
+    }
+}
+
 /// Lowered extra build dependencies with source resolution applied.
 #[derive(Debug, Clone, Default)]
 pub struct ExtraBuildRequires {
@@ -240,15 +394,18 @@ impl ExtraBuildRequires {
                     .map(ToolUvSources::inner)
                     .unwrap_or(&empty_sources);
 
-                // Lower each package's extra build dependencies
+                // Lower each package's extra build dependencies, preserving the dependency group
+                // a key is scoped to (if any) so group-qualified entries keep referring to that
+                // group after lowering, alongside any environment markers on the requirement
+                // itself.
                 let mut result = ExtraBuildDependencies::default();
-                for (package_name, requirements) in extra_build_dependencies {
+                for (key, requirements) in extra_build_dependencies {
+                    let group = key.group.clone();
                     let lowered: Vec<uv_pep508::Requirement<VerbatimParsedUrl>> = requirements
                         .into_iter()
                         .flat_map(|requirement| {
                             let requirement_name = requirement.name.clone();
                             let extra = requirement.marker.top_level_extra_name();
-                            let group = None;
                             LoweredRequirement::from_requirement(
                                 requirement,
                                 None,
@@ -256,7 +413,7 @@ impl ExtraBuildRequires {
                                 project_sources,
                                 project_indexes,
                                 extra.as_deref(),
-                                group,
+                                group.as_ref(),
                                 index_locations,
                                 workspace,
                                 None,
@@ -272,7 +429,7 @@ impl ExtraBuildRequires {
                             )
                         })
                         .collect::<Result<Vec<_>, _>>()?;
-                    result.insert(package_name, lowered);
+                    result.insert(key, lowered);
                 }
                 Ok(Self {
                     extra_build_dependencies: result,
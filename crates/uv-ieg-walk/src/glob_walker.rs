@@ -2,9 +2,172 @@ use bstr::{ByteSlice, ByteVec};
 use globset::{Glob, GlobSet, GlobSetBuilder};
 use regex_automata::dfa;
 use regex_automata::dfa::Automaton;
-use std::path::{Path, PathBuf, MAIN_SEPARATOR};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::path::{Component, Path, PathBuf, MAIN_SEPARATOR};
 use walkdir::{DirEntry, WalkDir};
 
+/// A cheap-to-evaluate classification of a single glob, ported from globset's internal
+/// `MatchStrategy`. See the corresponding dispatcher in `uv-build-backend`'s `globs.rs` for the
+/// rationale. Unlike that copy, this one assumes globs built by `parse_portable_glob` with
+/// `literal_separator(true)`, so its `*.ext` fast path only answers for root-level paths.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MatchStrategy {
+    /// The glob has no wildcards at all: it matches a single full path.
+    Literal(String),
+    /// The glob is `**/name` with no other wildcards: it matches any path with this basename.
+    BasenameLiteral(String),
+    /// The glob is `*.ext`: since `*` doesn't cross `/`, it matches a root-level path ending in
+    /// `.ext`.
+    Extension(String),
+    /// The glob is `dir/**`: it matches any path starting with `dir/`.
+    Prefix(String),
+    /// The glob is `**/a/b`: it matches any path ending in `/a/b`, anchored at a `/` boundary.
+    Suffix(String),
+    /// Anything else; falls back to the glob's compiled regex/matcher.
+    Regex,
+}
+
+impl MatchStrategy {
+    /// Classify a glob's source text into a fast-path strategy, falling back to
+    /// [`MatchStrategy::Regex`] for anything that doesn't fit one of the shapes below.
+    fn new(glob: &str) -> Self {
+        if !glob.contains(['*', '?', '[']) {
+            return Self::Literal(glob.to_string());
+        }
+
+        // The dot is not part of the stored key: `ext` is matched against a basename's
+        // extension as returned by `rsplit_once('.')`, which never includes the dot either.
+        // `ext` must not itself contain a `.`: `rsplit_once('.')` only ever returns the last
+        // component (`"gz"` for `archive.tar.gz`), so a multi-dot extension like `*.tar.gz` can
+        // never be recovered from a single `rsplit_once` and has to fall through to the regex.
+        if let Some(ext) = glob.strip_prefix("*.") {
+            if !ext.is_empty() && !ext.contains(['*', '?', '[', '/', '.']) {
+                return Self::Extension(ext.to_string());
+            }
+        }
+
+        if let Some(prefix) = glob.strip_suffix("/**") {
+            if !prefix.is_empty() && !prefix.contains(['*', '?', '[']) {
+                return Self::Prefix(format!("{prefix}/"));
+            }
+        }
+
+        if let Some(suffix) = glob.strip_prefix("**/") {
+            if !suffix.is_empty() && !suffix.contains(['*', '?', '[']) {
+                return if suffix.contains('/') {
+                    Self::Suffix(format!("/{suffix}"))
+                } else {
+                    Self::BasenameLiteral(suffix.to_string())
+                };
+            }
+        }
+
+        Self::Regex
+    }
+}
+
+/// Dispatches a candidate path to the cheapest applicable [`MatchStrategy`] computed from a set
+/// of globs, only falling back to the (expensive) glob set for the residual "complex" globs.
+struct MatchDispatcher {
+    literals: FxHashSet<String>,
+    basenames: FxHashSet<String>,
+    extensions: FxHashSet<String>,
+    prefixes: Vec<String>,
+    suffixes: Vec<String>,
+    /// Whether any glob didn't fit a fast-path strategy, in which case callers must still
+    /// consult the full glob set.
+    has_residual: bool,
+}
+
+impl MatchDispatcher {
+    fn new(globs: &[Glob]) -> Self {
+        let mut literals = FxHashSet::default();
+        let mut basenames = FxHashSet::default();
+        let mut extensions = FxHashSet::default();
+        let mut prefixes = Vec::new();
+        let mut suffixes = Vec::new();
+        let mut has_residual = false;
+        for glob in globs {
+            match MatchStrategy::new(glob.glob()) {
+                MatchStrategy::Literal(literal) => {
+                    literals.insert(literal);
+                }
+                MatchStrategy::BasenameLiteral(basename) => {
+                    basenames.insert(basename);
+                }
+                MatchStrategy::Extension(ext) => {
+                    extensions.insert(ext);
+                }
+                MatchStrategy::Prefix(prefix) => {
+                    prefixes.push(prefix);
+                }
+                MatchStrategy::Suffix(suffix) => {
+                    suffixes.push(suffix);
+                }
+                MatchStrategy::Regex => {
+                    has_residual = true;
+                }
+            }
+        }
+        Self {
+            literals,
+            basenames,
+            extensions,
+            prefixes,
+            suffixes,
+            has_residual,
+        }
+    }
+
+    /// Try to answer the match question from the fast-path strategies alone. Returns `None` when
+    /// none of them apply and the caller must fall back to the full glob set.
+    fn is_match(&self, path: &str) -> Option<bool> {
+        if self.literals.contains(path) {
+            return Some(true);
+        }
+
+        let basename = path.rsplit('/').next().unwrap_or(path);
+        if self.basenames.contains(basename) {
+            return Some(true);
+        }
+
+        // `parse_portable_glob` compiles with `literal_separator(true)` (a bare `*` never crosses
+        // `/`), so a `*.ext` glob only ever matches a root-level path with no `/` in it at all.
+        // Matching `basename`'s extension regardless of depth would be a false positive for any
+        // path under a subdirectory, so the fast path is only valid when `path == basename`.
+        if !self.extensions.is_empty() && path == basename {
+            if let Some(ext) = basename.rsplit_once('.').map(|(_, ext)| ext) {
+                if self.extensions.contains(ext) {
+                    return Some(true);
+                }
+            }
+        }
+
+        if self
+            .prefixes
+            .iter()
+            .any(|prefix| path.starts_with(prefix.as_str()))
+        {
+            return Some(true);
+        }
+
+        // The stored suffix carries its leading `/` so `ends_with` enforces the component
+        // boundary below the root, but `**/` also matches zero directories, so a root-level path
+        // equal to the suffix (without the leading `/`) must match too.
+        if self.suffixes.iter().any(|suffix| {
+            path.ends_with(suffix.as_str()) || path == suffix.trim_start_matches('/')
+        }) {
+            return Some(true);
+        }
+
+        if self.has_residual {
+            None
+        } else {
+            Some(false)
+        }
+    }
+}
+
 pub struct GlobWalkDir {
     root: PathBuf,
     matcher: GlobDirMatcher,
@@ -72,8 +235,78 @@ impl Iterator for GlobWalkerIntoIterator {
     }
 }
 
+/// A trie over the literal leading path components of a set of globs, recast from Mercurial's
+/// `VisitChildrenSet` optimization onto [`GlobDirMatcher`].
+///
+/// Descending into a directory during the walk is only worthwhile if some glob could still match
+/// a path below it. Walking the DFA answers that question, but costs a state transition per byte
+/// of the path; for most include lists the vast majority of directories are ruled out by their
+/// literal leading components alone, e.g. `foo/bar/**` rules out `baz` after looking only at its
+/// first component. The trie rules those directories out in O(depth) hash lookups; a directory
+/// the trie keeps is still checked against the DFA.
+#[derive(Debug, Default)]
+struct PrefixTrieNode {
+    children: FxHashMap<String, PrefixTrieNode>,
+    /// A `*`/`**` wildcard component starts here, so anything below this node might still match
+    /// and must be visited; the DFA (or fast-path dispatcher) is left to decide the rest.
+    wildcard: bool,
+}
+
+#[derive(Debug, Default)]
+struct PrefixTrie {
+    root: PrefixTrieNode,
+}
+
+impl PrefixTrie {
+    fn new(globs: &[Glob]) -> Self {
+        let mut root = PrefixTrieNode::default();
+        for glob in globs {
+            let mut node = &mut root;
+            for component in glob.glob().split('/') {
+                if component.contains(['*', '?', '[']) {
+                    node.wildcard = true;
+                    break;
+                }
+                node = node.children.entry(component.to_string()).or_default();
+            }
+        }
+        Self { root }
+    }
+
+    /// Whether `path`, a directory reached during the walk, could still lead to a match: either
+    /// it's still following the literal components of some glob, or it has already crossed a
+    /// wildcard component that could expand to match anything below.
+    ///
+    /// Invariant: a directory must be visited whenever any include glob could match a descendant
+    /// path, including through `**`, so this only ever prunes a subtree once every glob's literal
+    /// prefix has diverged from `path`.
+    fn visit(&self, path: &Path) -> bool {
+        let mut node = &self.root;
+        for component in path.components() {
+            let Component::Normal(component) = component else {
+                continue;
+            };
+            if node.wildcard {
+                return true;
+            }
+            let Some(component) = component.to_str() else {
+                // Non-UTF-8 components can't be a literal trie match; be conservative and let the
+                // DFA decide.
+                return true;
+            };
+            match node.children.get(component) {
+                Some(child) => node = child,
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
 pub struct GlobDirMatcher {
     glob_set: GlobSet,
+    dispatcher: MatchDispatcher,
+    prefix_trie: PrefixTrie,
     dfa: Option<dfa::dense::DFA<Vec<u32>>>,
 }
 
@@ -87,6 +320,8 @@ impl GlobDirMatcher {
             .build()
             // https://github.com/BurntSushi/ripgrep/discussions/2927
             .expect("globs can be combined to globset");
+        let dispatcher = MatchDispatcher::new(globs);
+        let prefix_trie = PrefixTrie::new(globs);
 
         let regexes: Vec<_> = globs
             .iter()
@@ -125,25 +360,49 @@ impl GlobDirMatcher {
             }
         };
 
-        Self { glob_set, dfa }
+        Self {
+            glob_set,
+            dispatcher,
+            prefix_trie,
+            dfa,
+        }
     }
 
     /// Whether the path matches any of the globs.
     pub fn match_path(&self, path: &Path) -> bool {
-        self.glob_set.is_match(path)
+        let Some(path) = path.to_str() else {
+            return self.glob_set.is_match(path);
+        };
+        self.is_match(path)
+    }
+
+    /// Whether the path matches any of the globs.
+    ///
+    /// Tries the cheap [`MatchStrategy`] dispatch first and only falls back to the compiled
+    /// [`GlobSet`] for globs that didn't fit a fast-path strategy.
+    pub fn is_match(&self, path: &str) -> bool {
+        self.dispatcher
+            .is_match(path)
+            .unwrap_or_else(|| self.glob_set.is_match(path))
     }
 
     /// Check whether a directory or any of its children has the option to be matched.
     pub fn match_directory(&self, path: &Path) -> bool {
-        let Some(dfa) = &self.dfa else {
-            return false;
-        };
-
         // Allow the root path
         if path == Path::new("") {
             return true;
         }
 
+        // Prune directories whose literal leading path components have already diverged from
+        // every glob, without paying for a DFA walk.
+        if !self.prefix_trie.visit(path) {
+            return false;
+        }
+
+        let Some(dfa) = &self.dfa else {
+            return false;
+        };
+
         let config_anchored =
             regex_automata::util::start::Config::new().anchored(regex_automata::Anchored::Yes);
         let mut state = dfa.start_state(&config_anchored).unwrap();
@@ -173,12 +432,47 @@ impl GlobDirMatcher {
 
 #[cfg(test)]
 mod tests {
+    use super::MatchDispatcher;
     use crate::glob_walker::{GlobDirMatcher, GlobWalkDir};
     use crate::portable_glob::parse_portable_glob;
+    use globset::Glob;
     use std::path::Path;
     use tempfile::tempdir;
     use walkdir::WalkDir;
 
+    #[test]
+    fn extension_glob_matches() {
+        // `parse_portable_glob` builds with `literal_separator(true)`, so `*` never crosses `/`:
+        // a bare `*.txt` only ever matches a root-level file, and the dispatcher (which reads
+        // only the glob text) must give the same answer for a path under a subdirectory.
+        let globs = [parse_portable_glob("*.txt").unwrap()];
+        let dispatcher = MatchDispatcher::new(&globs);
+        assert_eq!(dispatcher.is_match("LICENSE.txt"), Some(true));
+        assert_eq!(dispatcher.is_match("licenses/LICENSE.txt"), Some(false));
+        assert_eq!(dispatcher.is_match("LICENSE.md"), Some(false));
+    }
+
+    #[test]
+    fn multi_dot_extension_falls_back_to_residual() {
+        // `rsplit_once('.')` only ever recovers the last dot-component (`"gz"`, not `"tar.gz"`),
+        // so a multi-dot extension glob can't be classified as `MatchStrategy::Extension` without
+        // silently dropping the `tar.` part; it must fall through to the full glob set instead of
+        // being answered (incorrectly) by the fast path.
+        let globs = [parse_portable_glob("*.tar.gz").unwrap()];
+        let dispatcher = MatchDispatcher::new(&globs);
+        assert_eq!(dispatcher.is_match("archive.tar.gz"), None);
+        assert_eq!(dispatcher.is_match("archive.gz"), None);
+    }
+
+    #[test]
+    fn multi_component_suffix_matches_at_root() {
+        let globs = [Glob::new("**/a/b").unwrap()];
+        let dispatcher = MatchDispatcher::new(&globs);
+        assert_eq!(dispatcher.is_match("a/b"), Some(true));
+        assert_eq!(dispatcher.is_match("nested/a/b"), Some(true));
+        assert_eq!(dispatcher.is_match("a/c"), Some(false));
+    }
+
     const FILES: [&str; 5] = [
         "path1/dir1/subdir/a.txt",
         "path2/dir2/subdir/a.txt",
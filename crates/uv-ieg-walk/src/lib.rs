@@ -3,7 +3,15 @@
 //! The goal is globs that are portable between languages and operating systems.
 
 mod glob_walker;
+mod matcher;
 mod portable_glob;
 
 pub use glob_walker::{GlobDirMatcher, GlobWalkDir, GlobWalkerIntoIterator};
-pub use portable_glob::{check_portable_glob, parse_portable_glob, PortableGlobError};
+pub use matcher::{
+    AlwaysMatcher, DifferenceMatcher, IncludeMatcher, Matcher, NeverMatcher, UnionMatcher,
+    VisitChildren,
+};
+pub use portable_glob::{
+    check_portable_glob, parse_portable_glob, to_portable_regex, to_python_glob, Captures,
+    CapturingGlob, PortableGlobError,
+};
@@ -1,59 +1,62 @@
-use crate::glob_walker::{GlobDirMatcher, GlobWalkDir};
-use globset::GlobSetBuilder;
+use std::path::Path;
+
 use walkdir::WalkDir;
 
+use crate::glob_walker::GlobDirMatcher;
+use crate::matcher::{DifferenceMatcher, IncludeMatcher, Matcher, VisitChildren};
+
 mod glob_walker;
+mod matcher;
 mod portable_glob;
 
 fn main() {
     let includes = ["src/**", "third/*.py", "pyproject.toml", "foo/bar/**"];
-    let mut include_globs = Vec::new();
-    for include in includes {
-        let include = format!("{include}");
-        let glob = portable_glob::parse_portable_glob(&include).expect("TODO");
-        include_globs.push(glob.clone());
-    }
+    let include_globs: Vec<_> = includes
+        .iter()
+        .map(|include| portable_glob::parse_portable_glob(include).expect("TODO"))
+        .collect();
+    let include_matcher = IncludeMatcher::new(GlobDirMatcher::new(&include_globs));
 
     let excludes = ["__pycache__", "*.pyc", "*.pyo"];
-    let mut exclude_builder = GlobSetBuilder::new();
-    for exclude in excludes {
-        let exclude = if let Some(exclude) = exclude.strip_prefix("/") {
-            exclude.to_string()
-        } else {
-            format!("**/{exclude}").to_string()
-        };
-        let glob = portable_glob::parse_portable_glob(&exclude).expect("TODO");
-        exclude_builder.add(glob);
-    }
-    // https://github.com/BurntSushi/ripgrep/discussions/2927
-    let exclude_matcher = exclude_builder.build().expect("TODO");
+    let exclude_globs: Vec<_> = excludes
+        .iter()
+        .map(|exclude| {
+            let exclude = if let Some(exclude) = exclude.strip_prefix('/') {
+                exclude.to_string()
+            } else {
+                format!("**/{exclude}")
+            };
+            // https://github.com/BurntSushi/ripgrep/discussions/2927
+            portable_glob::parse_portable_glob(&exclude).expect("TODO")
+        })
+        .collect();
+    let exclude_matcher = IncludeMatcher::new(GlobDirMatcher::new(&exclude_globs));
 
-    let matcher = GlobDirMatcher::from_globs(&include_globs);
+    let matcher = DifferenceMatcher::new(include_matcher, exclude_matcher);
 
     let walkdir_root = "python";
-    for entry in WalkDir::new(walkdir_root)
-        .into_iter()
-        .filter_entry(|entry| {
-            // TODO(konsti): This is should be prettier.
-            let relative = entry
-                .path()
-                .strip_prefix(walkdir_root)
-                .expect("walkdir starts with root")
-                .to_path_buf();
-
-            matcher.match_directory(&relative) && !exclude_matcher.is_match(&relative)
-        })
-    {
+    for entry in WalkDir::new(walkdir_root).into_iter().filter_entry(|entry| {
+        let relative = entry
+            .path()
+            .strip_prefix(walkdir_root)
+            .expect("walkdir starts with root");
+        let parent = relative.parent().unwrap_or_else(|| Path::new(""));
+        match matcher.visit_children_set(parent) {
+            VisitChildren::All | VisitChildren::This => true,
+            VisitChildren::Empty => false,
+            set @ VisitChildren::Set(_) => relative
+                .file_name()
+                .is_some_and(|name| set.contains(&name.to_string_lossy())),
+        }
+    }) {
         let entry = entry.unwrap();
-        // TODO(konsti): This is should be prettier.
         let relative = entry
             .path()
             .strip_prefix(walkdir_root)
-            .expect("walkdir starts with root")
-            .to_path_buf();
+            .expect("walkdir starts with root");
 
-        if matcher.match_path(&relative) && !exclude_matcher.is_match(&relative) {
+        if matcher.matches(relative) {
             println!("{}", relative.display());
-        };
+        }
     }
 }
@@ -0,0 +1,214 @@
+//! A composable matcher algebra, modeled on Mercurial's matcher subsystem.
+//!
+//! This replaces the ad-hoc include/exclude wiring that used to be hand-rolled in `main.rs`: a
+//! two-phase `match_directory` + `match_path` check against the includes, with a separate
+//! exclude `GlobSet` consulted at both points and the `strip_prefix` dance duplicated at each
+//! call site. Composing [`Matcher`]s instead turns that into one declarative, testable tree.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::glob_walker::GlobDirMatcher;
+
+/// Which of a directory's direct children a [`Matcher`] might still match something under.
+///
+/// This lets a walker prune whole subtrees before descending into them (feeding `walkdir`'s
+/// `filter_entry`), instead of visiting every entry and filtering it out after the fact.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VisitChildren {
+    /// Every child may lead to a match; descend into all of them.
+    All,
+    /// This directory should be visited, but its children don't need individual consideration
+    /// (equivalent to `All` for pruning purposes; kept distinct to mirror Mercurial's algebra).
+    This,
+    /// Only these named children may lead to a match.
+    Set(HashSet<String>),
+    /// Nothing under this directory can match; prune the whole subtree.
+    Empty,
+}
+
+impl VisitChildren {
+    /// Whether `name` is one of the children worth descending into.
+    pub fn contains(&self, name: &str) -> bool {
+        match self {
+            Self::All | Self::This => true,
+            Self::Set(names) => names.contains(name),
+            Self::Empty => false,
+        }
+    }
+}
+
+/// A predicate over relative paths that can also report which subtrees are worth descending
+/// into, so a walker can prune a directory before stat-ing it rather than filtering it out
+/// after the fact.
+pub trait Matcher {
+    /// Whether `path` itself is included.
+    fn matches(&self, path: &Path) -> bool;
+
+    /// Which of `dir`'s direct children might still lead to a match.
+    fn visit_children_set(&self, dir: &Path) -> VisitChildren;
+}
+
+/// Matches every path.
+pub struct AlwaysMatcher;
+
+impl Matcher for AlwaysMatcher {
+    fn matches(&self, _path: &Path) -> bool {
+        true
+    }
+
+    fn visit_children_set(&self, _dir: &Path) -> VisitChildren {
+        VisitChildren::All
+    }
+}
+
+/// Matches no path.
+pub struct NeverMatcher;
+
+impl Matcher for NeverMatcher {
+    fn matches(&self, _path: &Path) -> bool {
+        false
+    }
+
+    fn visit_children_set(&self, _dir: &Path) -> VisitChildren {
+        VisitChildren::Empty
+    }
+}
+
+/// Matches paths included by a compiled glob set.
+pub struct IncludeMatcher {
+    globs: GlobDirMatcher,
+}
+
+impl IncludeMatcher {
+    pub fn new(globs: GlobDirMatcher) -> Self {
+        Self { globs }
+    }
+}
+
+impl Matcher for IncludeMatcher {
+    fn matches(&self, path: &Path) -> bool {
+        self.globs.match_path(path)
+    }
+
+    fn visit_children_set(&self, dir: &Path) -> VisitChildren {
+        if self.globs.match_directory(dir) {
+            VisitChildren::All
+        } else {
+            VisitChildren::Empty
+        }
+    }
+}
+
+/// Matches paths matched by `include` that are not also matched by `exclude`.
+pub struct DifferenceMatcher<I, E> {
+    include: I,
+    exclude: E,
+}
+
+impl<I: Matcher, E: Matcher> DifferenceMatcher<I, E> {
+    pub fn new(include: I, exclude: E) -> Self {
+        Self { include, exclude }
+    }
+}
+
+impl<I: Matcher, E: Matcher> Matcher for DifferenceMatcher<I, E> {
+    fn matches(&self, path: &Path) -> bool {
+        self.include.matches(path) && !self.exclude.matches(path)
+    }
+
+    fn visit_children_set(&self, dir: &Path) -> VisitChildren {
+        // An exclude only ever removes matches, it never adds any, so pruning is driven entirely
+        // by `include`: a directory that `exclude` would blanket-reject can still contain files
+        // a narrower exclude glob doesn't reach, so we can't prune on `exclude` alone.
+        self.include.visit_children_set(dir)
+    }
+}
+
+/// Matches paths matched by any of its inner matchers.
+pub struct UnionMatcher {
+    matchers: Vec<Box<dyn Matcher>>,
+}
+
+impl UnionMatcher {
+    pub fn new(matchers: Vec<Box<dyn Matcher>>) -> Self {
+        Self { matchers }
+    }
+}
+
+impl Matcher for UnionMatcher {
+    fn matches(&self, path: &Path) -> bool {
+        self.matchers.iter().any(|matcher| matcher.matches(path))
+    }
+
+    fn visit_children_set(&self, dir: &Path) -> VisitChildren {
+        let mut result = VisitChildren::Empty;
+        for matcher in &self.matchers {
+            result = union_visit_children(result, matcher.visit_children_set(dir));
+            if result == VisitChildren::All {
+                break;
+            }
+        }
+        result
+    }
+}
+
+/// Combine two [`VisitChildren`] results as a union: descend wherever either side would.
+fn union_visit_children(a: VisitChildren, b: VisitChildren) -> VisitChildren {
+    match (a, b) {
+        (VisitChildren::All, _) | (_, VisitChildren::All) => VisitChildren::All,
+        (VisitChildren::Empty, other) | (other, VisitChildren::Empty) => other,
+        (VisitChildren::This, VisitChildren::This) => VisitChildren::This,
+        (VisitChildren::This, VisitChildren::Set(set))
+        | (VisitChildren::Set(set), VisitChildren::This) => VisitChildren::Set(set),
+        (VisitChildren::Set(mut a), VisitChildren::Set(b)) => {
+            a.extend(b);
+            VisitChildren::Set(a)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::portable_glob::parse_portable_glob;
+
+    #[test]
+    fn difference_excludes_matches_but_not_descent() {
+        let include = IncludeMatcher::new(GlobDirMatcher::new(&[
+            parse_portable_glob("src/**").unwrap()
+        ]));
+        let exclude = IncludeMatcher::new(GlobDirMatcher::new(&[
+            parse_portable_glob("**/*.pyc").unwrap()
+        ]));
+        let matcher = DifferenceMatcher::new(include, exclude);
+
+        assert!(matcher.matches(Path::new("src/foo.py")));
+        assert!(!matcher.matches(Path::new("src/foo.pyc")));
+        // Descent is still driven by `include` alone.
+        assert_eq!(
+            matcher.visit_children_set(Path::new("src")),
+            VisitChildren::All
+        );
+    }
+
+    #[test]
+    fn union_of_empty_is_empty() {
+        let matcher = UnionMatcher::new(vec![Box::new(NeverMatcher), Box::new(NeverMatcher)]);
+        assert_eq!(
+            matcher.visit_children_set(Path::new("anything")),
+            VisitChildren::Empty
+        );
+        assert!(!matcher.matches(Path::new("anything")));
+    }
+
+    #[test]
+    fn union_with_always_is_all() {
+        let matcher = UnionMatcher::new(vec![Box::new(NeverMatcher), Box::new(AlwaysMatcher)]);
+        assert_eq!(
+            matcher.visit_children_set(Path::new("anything")),
+            VisitChildren::All
+        );
+        assert!(matcher.matches(Path::new("anything")));
+    }
+}
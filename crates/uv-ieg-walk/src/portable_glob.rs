@@ -1,4 +1,5 @@
 use globset::{Glob, GlobBuilder};
+use regex::Regex;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -26,6 +27,77 @@ pub enum PortableGlobError {
     TooManyStars { glob: String, pos: usize },
 }
 
+/// Rich [`miette::Diagnostic`] rendering for [`PortableGlobError`], so tools embedding this crate
+/// can print a caret pointing at the exact bad character instead of just the flat `Display`
+/// message. This is additive: the plain `Display` output above is untouched, so snapshot tests
+/// that only check `.to_string()` keep passing byte-for-byte.
+#[cfg(feature = "diagnostics")]
+mod diagnostics {
+    use super::PortableGlobError;
+    use miette::{Diagnostic, LabeledSpan, SourceSpan};
+
+    impl Diagnostic for PortableGlobError {
+        fn help<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+            let help: &'static str = match self {
+                Self::GlobError(_) => return None,
+                Self::ParentDirectory { .. } => {
+                    "PEP 639 forbids the parent directory operator (`..`) in restricted globs"
+                }
+                Self::InvalidCharacter { .. } => {
+                    "only alphanumerics, `_`, `-`, `.`, `/`, `*`, `**`, `?` and `[...]` ranges are \
+                     allowed in a restricted glob"
+                }
+                Self::InvalidCharacterRange { .. } => {
+                    "a `[...]` range may only contain alphanumerics, `_`, `-` and `.`"
+                }
+                Self::TooManyStars { .. } => {
+                    "`***` and `**` followed by a literal are ambiguous; use `*` or `**/`"
+                }
+            };
+            Some(Box::new(help))
+        }
+
+        fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+            match self {
+                Self::GlobError(_) => None,
+                Self::ParentDirectory { glob, .. }
+                | Self::InvalidCharacter { glob, .. }
+                | Self::InvalidCharacterRange { glob, .. }
+                | Self::TooManyStars { glob, .. } => Some(glob),
+            }
+        }
+
+        fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+            let (span, label) = match self {
+                Self::GlobError(_) => return None,
+                Self::ParentDirectory { pos, .. } => {
+                    (SourceSpan::from((*pos, 2)), "parent directory operator")
+                }
+                Self::InvalidCharacter { pos, .. } => (SourceSpan::from((*pos, 1)), "invalid character"),
+                Self::InvalidCharacterRange { glob, pos, .. } => (
+                    bracket_span(glob, *pos),
+                    "invalid character in character range",
+                ),
+                Self::TooManyStars { pos, .. } => (SourceSpan::from((*pos, 1)), "too many stars"),
+            };
+            Some(Box::new(std::iter::once(LabeledSpan::new_with_span(
+                Some(label.to_string()),
+                span,
+            ))))
+        }
+    }
+
+    /// Expand a position inside a `[...]` character range into a span covering the whole range,
+    /// so the label highlights `[C?]` rather than just the offending `?`.
+    fn bracket_span(glob: &str, pos: usize) -> SourceSpan {
+        let start = glob[..pos].rfind('[').unwrap_or(pos);
+        let end = glob[pos..]
+            .find(']')
+            .map_or(glob.len(), |offset| pos + offset + 1);
+        SourceSpan::from(start..end)
+    }
+}
+
 pub fn parse_portable_glob(glob: &str) -> Result<Glob, PortableGlobError> {
     check_portable_glob(glob)?;
     Ok(GlobBuilder::new(glob).literal_separator(true).build()?)
@@ -104,3 +176,188 @@ pub fn check_portable_glob(glob: &str) -> Result<(), PortableGlobError> {
     }
     Ok(())
 }
+
+/// A compiled portable glob that additionally records what each wildcard token matched, analogous
+/// to the capture support the `wax` glob crate exposes.
+///
+/// A plain [`parse_portable_glob`] glob is non-capturing and allocation-free to match; building a
+/// [`CapturingGlob`] instead wraps every `*`, `**`, `?` and `[...]` token in its own capture group,
+/// so it costs more to compile and to match. Capturing is opt-in: reach for this type only when the
+/// matched text is actually needed, e.g. to reuse a `**` segment when staging build-backend output
+/// files, or to derive a license identifier from the name matched by `licenses/*.txt`.
+pub struct CapturingGlob {
+    regex: Regex,
+}
+
+impl CapturingGlob {
+    /// Compile `glob` into a capturing matcher.
+    pub fn new(glob: &str) -> Result<Self, PortableGlobError> {
+        check_portable_glob(glob)?;
+        let pattern = to_capturing_regex(glob);
+        let regex = Regex::new(&pattern).expect("translated portable glob is a valid regex");
+        Ok(Self { regex })
+    }
+
+    /// Match `path` against the glob, returning the text captured by each wildcard token on
+    /// success.
+    pub fn captures<'t>(&self, path: &'t str) -> Option<Captures<'t>> {
+        self.regex.captures(path).map(Captures)
+    }
+}
+
+/// The text matched by each wildcard token of a [`CapturingGlob`] pattern, indexed in the order
+/// the tokens appear in the glob.
+pub struct Captures<'t>(regex::Captures<'t>);
+
+impl<'t> Captures<'t> {
+    /// The text matched by the `index`-th wildcard token (0-based).
+    ///
+    /// Returns `None` if there is no token at `index`, or if an optional `**/` token didn't
+    /// participate in the match (e.g. `**/foo` matching bare `foo`).
+    pub fn get(&self, index: usize) -> Option<&'t str> {
+        self.0.get(index + 1).map(|capture| capture.as_str())
+    }
+
+    /// The number of wildcard tokens with a capture slot.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.0.len() - 1
+    }
+}
+
+/// Translate a validated portable glob into an equivalent regex where every wildcard token is
+/// wrapped in its own capture group.
+///
+/// Since [`check_portable_glob`] already guarantees the restricted PEP 639 grammar, this only has
+/// to handle that small token set: literal runs (escaped), `*`, `**` (optionally folding a
+/// trailing `/` into the group so a root-level match doesn't leave a dangling separator),  `?`,
+/// and `[...]` ranges, which are passed through verbatim since the grammar only allows literal
+/// characters and `-` ranges inside them.
+fn to_capturing_regex(glob: &str) -> String {
+    let mut pattern = String::from("^");
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    pattern.push_str("(?:(.*)/)?");
+                } else {
+                    pattern.push_str("(.*)");
+                }
+            }
+            '*' => pattern.push_str("([^/]*)"),
+            '?' => pattern.push_str("([^/])"),
+            '[' => {
+                pattern.push_str("([");
+                for c in chars.by_ref() {
+                    pattern.push(c);
+                    if c == ']' {
+                        break;
+                    }
+                }
+                pattern.push(')');
+            }
+            '/' => pattern.push('/'),
+            c => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    pattern.push('$');
+    pattern
+}
+
+/// Render `glob` as an equivalent regex in a neutral ECMAScript/PCRE-compatible dialect, so other
+/// toolchains embedding uv can consume the exact same `license-files`/build-include patterns
+/// without re-implementing the PEP 639 grammar.
+///
+/// Guaranteed semantics: the emitted pattern is anchored with `^`/`$`, matches forward-slash-only
+/// paths, and never needs to express `..`, since [`check_portable_glob`] already rejects it.
+/// Because the validator guarantees the restricted grammar, the translator only has to handle a
+/// small token set: `*/` becomes `(?:[^/]*/)`, a standalone `*` becomes `[^/]*`, `**/` becomes
+/// `(?:.*/)?`, `?` becomes `[^/]`, `[...]` ranges pass through verbatim, and every other character
+/// is escaped before being copied into the pattern.
+pub fn to_portable_regex(glob: &str) -> String {
+    let mut pattern = String::from("^");
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    pattern.push_str("(?:.*/)?");
+                } else {
+                    pattern.push_str(".*");
+                }
+            }
+            '*' if chars.peek() == Some(&'/') => {
+                chars.next();
+                pattern.push_str("(?:[^/]*/)");
+            }
+            '*' => pattern.push_str("[^/]*"),
+            '?' => pattern.push_str("[^/]"),
+            '[' => {
+                pattern.push('[');
+                for c in chars.by_ref() {
+                    pattern.push(c);
+                    if c == ']' {
+                        break;
+                    }
+                }
+            }
+            '/' => pattern.push('/'),
+            c => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    pattern.push('$');
+    pattern
+}
+
+/// Render `glob` as an equivalent Python glob pattern.
+///
+/// PEP 639's restricted grammar was deliberately chosen to already be a valid glob in every
+/// ecosystem it targets, so for Python this is the identity function: the same string can be
+/// passed straight to `pathlib.Path.glob()`, which understands `**` recursively and matches this
+/// module's semantics exactly. `fnmatch.translate()` also accepts the string, but treats `**` as
+/// a plain `*` (a strictly looser match), so callers that need `**` to mean "any number of
+/// directories" specifically should prefer `pathlib` over `fnmatch`.
+pub fn to_python_glob(glob: &str) -> String {
+    glob.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+
+    #[test]
+    fn portable_regex_matches_same_paths_as_globset() {
+        let cases = [
+            ("src/**/*.py", "src/pkg/mod.py", true),
+            ("src/**/*.py", "src/mod.py", true),
+            ("src/**/*.py", "other/mod.py", false),
+            ("licenses/*.txt", "licenses/MIT.txt", true),
+            ("licenses/*.txt", "licenses/sub/MIT.txt", false),
+            ("LICEN[CS]E.txt", "LICENSE.txt", true),
+            ("LICEN[CS]E.txt", "LICENCE.txt", true),
+            ("LICEN[CS]E.txt", "LICENXE.txt", false),
+            ("foo/bar/**", "foo/bar/baz", true),
+        ];
+        for (glob, path, expected) in cases {
+            let compiled = parse_portable_glob(glob).unwrap().compile_matcher();
+            let regex = Regex::new(&to_portable_regex(glob)).unwrap();
+            assert_eq!(compiled.is_match(path), expected, "globset mismatch for {glob} vs {path}");
+            assert_eq!(
+                regex.is_match(path),
+                expected,
+                "to_portable_regex mismatch for {glob} vs {path}"
+            );
+        }
+    }
+
+    #[test]
+    fn python_glob_is_identity() {
+        assert_eq!(to_python_glob("src/**/*.py"), "src/**/*.py");
+    }
+}
@@ -63,19 +63,159 @@ pub(crate) struct GlobalSettings {
     pub(crate) color: ColorChoice,
     pub(crate) network_settings: NetworkSettings,
     pub(crate) concurrency: Concurrency,
+    /// Bounds for adaptive download concurrency, if [`ConcurrencyMode::Auto`] is selected; see
+    /// [`AdaptiveConcurrencySettings`]. This field is resolved but never read.
+    pub(crate) adaptive_concurrency: Option<AdaptiveConcurrencySettings>,
     pub(crate) show_settings: bool,
     pub(crate) preview: Preview,
     pub(crate) python_preference: PythonPreference,
     pub(crate) python_downloads: PythonDownloads,
     pub(crate) no_progress: bool,
     pub(crate) installer_metadata: bool,
+    /// The name of the active `--profile`/`UV_PROFILE` selection, if any. Kept around so
+    /// downstream `*Settings::resolve` calls (e.g. [`RunSettings::resolve`]) can look the same
+    /// profile back up in `[tool.uv.profiles.<name>]` for their own options.
+    pub(crate) profile: Option<String>,
+    /// How to react when the running `uv` binary doesn't satisfy `required_version`.
+    ///
+    /// Non-functional plumbing, not a completed feature: this checkout's `crates/uv/src` contains
+    /// only this settings module, with no `main`/command-dispatch entry point for anything to read
+    /// this field from, so `require_version_mode` is resolved but never consulted anywhere.
+    pub(crate) require_version_mode: RequiredVersionMode,
+    /// Mirror overrides consulted when [`RequiredVersionMode::Download`] needs to fetch a
+    /// different `uv` release.
+    ///
+    /// Same caveat as [`Self::require_version_mode`]: there is no download/re-exec call site in
+    /// this checkout to consult these mirrors, so this field is resolved but unread.
+    pub(crate) uv_install_mirrors: UvInstallMirrors,
+}
+
+/// How to react when the running `uv` binary doesn't satisfy `required_version`.
+///
+/// Only the mode selection is resolved here. No code in this module checks the running version
+/// against `required_version`, downloads a `uv` release, or re-execs one, so selecting
+/// [`RequiredVersionMode::Download`] has the same effect as [`RequiredVersionMode::Error`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RequiredVersionMode {
+    /// Fail with an error if the running `uv` doesn't satisfy `required_version`.
+    #[default]
+    Error,
+    /// Select downloading and re-executing the `uv` release matching `required_version`. The
+    /// selection is resolved but not acted on; see [`RequiredVersionMode`].
+    Download,
+}
+
+impl FromStr for RequiredVersionMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Self::Error),
+            "download" => Ok(Self::Download),
+            _ => Err(format!(
+                "invalid require-version-mode `{s}`, expected `error` or `download`"
+            )),
+        }
+    }
+}
+
+/// The selected download concurrency mode: a fixed count (the default, set via
+/// `UV_CONCURRENT_DOWNLOADS`, the active profile, or `[tool.uv]`), or `auto`, selected via
+/// `UV_CONCURRENCY=auto`, the active profile, or `[tool.uv]`. Selecting `auto` only resolves
+/// [`AdaptiveConcurrencySettings`]; [`Concurrency::downloads`] stays a fixed count in both modes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum ConcurrencyMode {
+    #[default]
+    Fixed,
+    Auto,
+}
+
+impl FromStr for ConcurrencyMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "fixed" => Ok(Self::Fixed),
+            _ => Err(format!(
+                "invalid value for UV_CONCURRENCY: `{s}`, expected `auto` or `fixed`"
+            )),
+        }
+    }
+}
+
+/// Bounds for adaptive download concurrency, resolved when [`ConcurrencyMode::Auto`] is selected.
+///
+/// `floor` and `ceiling` come from `UV_CONCURRENCY_MIN`/`UV_CONCURRENCY_MAX`, the active profile,
+/// or `[tool.uv]`, defaulting to 1 and 50. Nothing adjusts the download limit between these
+/// bounds: [`Concurrency::downloads`] stays a fixed value, and `builds` and `installs` are not
+/// affected by this setting.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AdaptiveConcurrencySettings {
+    pub(crate) floor: usize,
+    pub(crate) ceiling: usize,
+}
+
+impl AdaptiveConcurrencySettings {
+    const DEFAULT_FLOOR: usize = 1;
+    const DEFAULT_CEILING: usize = 50;
+
+    /// Resolve the adaptive concurrency bounds, if `auto` is selected by `UV_CONCURRENCY`, the
+    /// active profile, or `[tool.uv]` (in that order of precedence; no command-line argument is
+    /// read); otherwise `None`.
+    fn resolve(
+        workspace: Option<&FilesystemOptions>,
+        profile_options: Option<&Options>,
+    ) -> Option<Self> {
+        let mode = env(env::CONCURRENCY)
+            .combine(profile_options.and_then(|profile| profile.globals.concurrency_mode))
+            .combine(workspace.and_then(|workspace| workspace.globals.concurrency_mode))
+            .unwrap_or_default();
+        if mode != ConcurrencyMode::Auto {
+            return None;
+        }
+        Some(Self {
+            floor: env(env::CONCURRENCY_MIN)
+                .combine(profile_options.and_then(|profile| profile.globals.concurrency_min))
+                .combine(workspace.and_then(|workspace| workspace.globals.concurrency_min))
+                .unwrap_or(Self::DEFAULT_FLOOR),
+            ceiling: env(env::CONCURRENCY_MAX)
+                .combine(profile_options.and_then(|profile| profile.globals.concurrency_max))
+                .combine(workspace.and_then(|workspace| workspace.globals.concurrency_max))
+                .unwrap_or(Self::DEFAULT_CEILING),
+        })
+    }
+}
+
+/// Overrides for where to download a `uv` release when [`RequiredVersionMode::Download`] needs to
+/// bootstrap a version other than the one currently running.
+///
+/// Mirrors the [`PythonInstallMirrors`] plumbing pattern uv already uses for Python toolchains, so
+/// locked-down environments can point both at an internal host with the same mechanism.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct UvInstallMirrors {
+    pub(crate) uv_install_mirror: Option<String>,
+}
+
+impl UvInstallMirrors {
+    /// Resolve the [`UvInstallMirrors`] from the CLI and filesystem configuration.
+    fn resolve(uv_install_mirror: Option<String>, workspace: Option<&FilesystemOptions>) -> Self {
+        Self {
+            uv_install_mirror: uv_install_mirror
+                .or_else(|| env(env::UV_INSTALL_MIRROR))
+                .or_else(|| workspace.and_then(|workspace| workspace.globals.uv_install_mirror.clone())),
+        }
+    }
 }
 
 impl GlobalSettings {
     /// Resolve the [`GlobalSettings`] from the CLI and filesystem configuration.
     pub(crate) fn resolve(args: &GlobalArgs, workspace: Option<&FilesystemOptions>) -> Self {
-        let network_settings = NetworkSettings::resolve(args, workspace);
-        let python_preference = resolve_python_preference(args, workspace);
+        let profile = resolve_profile_name(args);
+        let profile_options = active_profile_options(profile.as_deref(), workspace);
+
+        let network_settings = NetworkSettings::resolve(args, workspace, profile_options);
+        let python_preference = resolve_python_preference(args, workspace, profile_options);
         Self {
             required_version: workspace
                 .and_then(|workspace| workspace.globals.required_version.clone()),
@@ -103,23 +243,32 @@ impl GlobalSettings {
                 ColorChoice::Auto
             },
             network_settings,
+            // `concurrent-downloads`/`concurrent-builds`/`concurrent-installs` in `[tool.uv]`
+            // (via `workspace.globals`) let CI-heavy or bandwidth-constrained projects pin sane
+            // limits in `pyproject.toml`; the `UV_CONCURRENT_*` environment variables still win
+            // over both the active profile and the base workspace configuration.
             concurrency: Concurrency {
                 downloads: env(env::CONCURRENT_DOWNLOADS)
+                    .combine(profile_options.and_then(|profile| profile.globals.concurrent_downloads))
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_downloads))
                     .map(NonZeroUsize::get)
                     .unwrap_or(Concurrency::DEFAULT_DOWNLOADS),
                 builds: env(env::CONCURRENT_BUILDS)
+                    .combine(profile_options.and_then(|profile| profile.globals.concurrent_builds))
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_builds))
                     .map(NonZeroUsize::get)
                     .unwrap_or_else(Concurrency::threads),
                 installs: env(env::CONCURRENT_INSTALLS)
+                    .combine(profile_options.and_then(|profile| profile.globals.concurrent_installs))
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_installs))
                     .map(NonZeroUsize::get)
                     .unwrap_or_else(Concurrency::threads),
             },
+            adaptive_concurrency: AdaptiveConcurrencySettings::resolve(workspace, profile_options),
             show_settings: args.show_settings,
             preview: Preview::from_args(
                 flag(args.preview, args.no_preview, "preview")
+                    .combine(profile_options.and_then(|profile| profile.globals.preview))
                     .combine(workspace.and_then(|workspace| workspace.globals.preview))
                     .unwrap_or(false),
                 args.no_preview,
@@ -133,19 +282,142 @@ impl GlobalSettings {
             )
             .map(PythonDownloads::from)
             .combine(env(env::UV_PYTHON_DOWNLOADS))
+            .combine(profile_options.and_then(|profile| profile.globals.python_downloads))
             .combine(workspace.and_then(|workspace| workspace.globals.python_downloads))
             .unwrap_or_default(),
             // Disable the progress bar with `RUST_LOG` to avoid progress fragments interleaving
             // with log messages.
             no_progress: args.no_progress || std::env::var_os(EnvVars::RUST_LOG).is_some(),
             installer_metadata: !args.no_installer_metadata,
+            require_version_mode: env(env::UV_REQUIRE_VERSION_MODE)
+                .combine(profile_options.and_then(|profile| profile.globals.require_version_mode))
+                .combine(workspace.and_then(|workspace| workspace.globals.require_version_mode))
+                .unwrap_or_default(),
+            uv_install_mirrors: UvInstallMirrors::resolve(args.uv_install_mirror.clone(), workspace),
+            profile,
+        }
+    }
+}
+
+/// Resolve the name of the active configuration profile from `--profile` or `UV_PROFILE`.
+///
+/// This only resolves the *name*; [`active_profile_options`] looks the name back up in
+/// `[tool.uv.profiles.<name>]` once a [`FilesystemOptions`] is available.
+fn resolve_profile_name(args: &GlobalArgs) -> Option<String> {
+    args.profile.clone().or_else(|| env(env::PROFILE))
+}
+
+/// Look up the active profile's options block, if a profile is selected and defined.
+///
+/// An unknown profile name (selected but not present in `[tool.uv.profiles]`) is treated the same
+/// as no profile being active, since CLI parsing is expected to have already validated the name
+/// against the loaded workspace before we get here.
+fn active_profile_options<'a>(
+    profile: Option<&str>,
+    workspace: Option<&'a FilesystemOptions>,
+) -> Option<&'a Options> {
+    workspace?.profiles.get(profile?)
+}
+
+/// A single alias's replacement value in `[tool.uv.aliases]`: either a single command string,
+/// split on whitespace, or an explicit list of argv tokens.
+#[derive(Debug, Clone)]
+pub(crate) enum AliasSpec {
+    String(String),
+    List(Vec<String>),
+}
+
+impl AliasSpec {
+    fn into_tokens(self) -> Vec<String> {
+        match self {
+            Self::String(s) => s.split_whitespace().map(str::to_string).collect(),
+            Self::List(tokens) => tokens,
+        }
+    }
+}
+
+/// An error encountered while expanding `[tool.uv.aliases]`.
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum AliasError {
+    /// An alias expanded back to a name already seen earlier in the same expansion chain.
+    #[error("alias `{0}` expands to itself, directly or transitively")]
+    Cycle(String),
+}
+
+/// User-defined command aliases from `[tool.uv.aliases]`, expanded before CLI argument parsing.
+///
+/// Mirrors cargo's `[alias]` table: each entry maps a shorthand first token (e.g. `ci`) to the
+/// argv it stands in for (e.g. `pip install --require-hashes --no-deps`). [`Self::expand`] works
+/// on the raw process argv, not on parsed arguments; no `*Settings::resolve` in this module calls
+/// it.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CommandAliases {
+    aliases: std::collections::HashMap<String, AliasSpec>,
+}
+
+impl CommandAliases {
+    /// Load the alias table from `[tool.uv.aliases]`, then layer any `UV_ALIAS_<NAME>`
+    /// environment variables on top (e.g. `UV_ALIAS_CI=pip install --require-hashes --no-deps`
+    /// defines/overrides the `ci` alias). The environment always wins, consistent with every
+    /// other setting resolved in this module.
+    pub(crate) fn from_workspace(workspace: Option<&FilesystemOptions>) -> Self {
+        let mut aliases = workspace
+            .map(|workspace| workspace.aliases.clone())
+            .unwrap_or_default();
+
+        for (name, value) in std::env::vars() {
+            let Some(name) = name.strip_prefix("UV_ALIAS_") else {
+                continue;
+            };
+            if name.is_empty() {
+                continue;
+            }
+            aliases.insert(name.to_lowercase(), AliasSpec::String(value));
         }
+
+        Self { aliases }
+    }
+
+    /// Expand `args`' first token if it names an alias, recursively, stopping as soon as the
+    /// current first token is a built-in subcommand or isn't aliased. An alias is never allowed
+    /// to shadow a name in `builtin_subcommands`.
+    ///
+    /// `args` is the full process argv, including the binary name at index 0.
+    pub(crate) fn expand(
+        &self,
+        args: Vec<String>,
+        builtin_subcommands: &[&str],
+    ) -> Result<Vec<String>, AliasError> {
+        let Some((program, rest)) = args.split_first() else {
+            return Ok(args);
+        };
+
+        let mut expanded = rest.to_vec();
+        let mut seen = std::collections::HashSet::new();
+        while let Some(first) = expanded.first() {
+            if builtin_subcommands.contains(&first.as_str()) {
+                break;
+            }
+            let Some(spec) = self.aliases.get(first) else {
+                break;
+            };
+            if !seen.insert(first.clone()) {
+                return Err(AliasError::Cycle(first.clone()));
+            }
+            expanded.splice(0..1, spec.clone().into_tokens());
+        }
+
+        let mut result = Vec::with_capacity(expanded.len() + 1);
+        result.push(program.clone());
+        result.extend(expanded);
+        Ok(result)
     }
 }
 
 fn resolve_python_preference(
     args: &GlobalArgs,
     workspace: Option<&FilesystemOptions>,
+    profile: Option<&Options>,
 ) -> PythonPreference {
     if args.managed_python {
         PythonPreference::OnlyManaged
@@ -153,6 +425,7 @@ fn resolve_python_preference(
         PythonPreference::OnlySystem
     } else {
         args.python_preference
+            .combine(profile.and_then(|profile| profile.globals.python_preference))
             .combine(workspace.and_then(|workspace| workspace.globals.python_preference))
             .unwrap_or_default()
     }
@@ -164,11 +437,21 @@ pub(crate) struct NetworkSettings {
     pub(crate) connectivity: Connectivity,
     pub(crate) native_tls: bool,
     pub(crate) allow_insecure_host: Vec<TrustedHost>,
+    pub(crate) http_timeout: Option<std::time::Duration>,
+    pub(crate) http_retries: u32,
 }
 
 impl NetworkSettings {
-    pub(crate) fn resolve(args: &GlobalArgs, workspace: Option<&FilesystemOptions>) -> Self {
+    /// The default number of times to retry a failed HTTP request, absent `UV_HTTP_RETRIES`.
+    const DEFAULT_HTTP_RETRIES: u32 = 3;
+
+    pub(crate) fn resolve(
+        args: &GlobalArgs,
+        workspace: Option<&FilesystemOptions>,
+        profile: Option<&Options>,
+    ) -> Self {
         let connectivity = if flag(args.offline, args.no_offline, "offline")
+            .combine(profile.and_then(|profile| profile.globals.offline))
             .combine(workspace.and_then(|workspace| workspace.globals.offline))
             .unwrap_or(false)
         {
@@ -177,6 +460,7 @@ impl NetworkSettings {
             Connectivity::Online
         };
         let native_tls = flag(args.native_tls, args.no_native_tls, "native-tls")
+            .combine(profile.and_then(|profile| profile.globals.native_tls))
             .combine(workspace.and_then(|workspace| workspace.globals.native_tls))
             .unwrap_or(false);
         let allow_insecure_host = args
@@ -195,11 +479,21 @@ impl NetworkSettings {
                     .into_iter()
                     .flatten(),
             )
+            .chain(
+                env_array::<TrustedHost>(env::UV_INSECURE_HOST, ',')
+                    .into_iter()
+                    .flatten(),
+            )
             .collect();
+        let http_timeout: Option<EnvDuration> = env(env::UV_HTTP_TIMEOUT);
+        let http_timeout = http_timeout.map(|duration| duration.0);
+        let http_retries = env_or(env::UV_HTTP_RETRIES, Self::DEFAULT_HTTP_RETRIES);
         Self {
             connectivity,
             native_tls,
             allow_insecure_host,
+            http_timeout,
+            http_retries,
         }
     }
 }
@@ -209,11 +503,15 @@ impl NetworkSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    /// The maximum size the cache is allowed to grow to, e.g. `UV_CACHE_SIZE=2GiB`, before uv
+    /// starts evicting entries. `None` means unbounded.
+    pub(crate) cache_size: Option<u64>,
 }
 
 impl CacheSettings {
     /// Resolve the [`CacheSettings`] from the CLI and filesystem configuration.
     pub(crate) fn resolve(args: CacheArgs, workspace: Option<&FilesystemOptions>) -> Self {
+        let cache_size: Option<EnvByteSize> = env(env::UV_CACHE_SIZE);
         Self {
             no_cache: args.no_cache
                 || workspace
@@ -222,6 +520,7 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            cache_size: cache_size.map(|size| size.0),
         }
     }
 }
@@ -314,6 +613,118 @@ impl InitSettings {
     }
 }
 
+/// A single `KEY=VALUE` pair loaded from a `--env-file`, after `${VAR}` / `${VAR:-default}`
+/// expansion.
+#[derive(Debug, Clone)]
+pub(crate) struct EnvFileVariable {
+    pub(crate) key: String,
+    pub(crate) value: String,
+}
+
+/// The environment variables resolved from one or more layered `--env-file` paths.
+///
+/// Files are applied in the order given, so a variable defined in a later file overrides the
+/// same variable defined in an earlier one. Whether these variables take precedence over an
+/// identically-named variable already present in the process environment is controlled by
+/// `override_process_env` (`--env-file-override`); by default, the process environment wins.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EnvFileSettings {
+    pub(crate) variables: Vec<EnvFileVariable>,
+    pub(crate) override_process_env: bool,
+}
+
+impl EnvFileSettings {
+    /// Resolve the [`EnvFileSettings`] by reading and expanding each `--env-file` in order.
+    ///
+    /// Parse errors are reported with the offending file and line number and exit the process
+    /// immediately, rather than surfacing only once the environment is actually applied at exec
+    /// time.
+    fn resolve(paths: &[PathBuf], no_env_file: bool, override_process_env: bool) -> Self {
+        if no_env_file {
+            return Self::default();
+        }
+
+        let mut variables: Vec<EnvFileVariable> = Vec::new();
+        for path in paths {
+            let contents = fs_err::read_to_string(path).unwrap_or_else(|err| {
+                eprintln!("error: failed to read env file `{}`: {err}", path.display());
+                process::exit(1)
+            });
+            for (index, line) in contents.lines().enumerate() {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                let Some((key, raw_value)) = line.split_once('=') else {
+                    eprintln!(
+                        "error: invalid line in env file `{}:{}`: expected `KEY=VALUE`, found `{line}`",
+                        path.display(),
+                        index + 1
+                    );
+                    process::exit(1)
+                };
+                let value = expand_env_file_value(raw_value.trim(), &variables).unwrap_or_else(|name| {
+                    eprintln!(
+                        "error: env file `{}:{}` references undefined variable `{name}`",
+                        path.display(),
+                        index + 1
+                    );
+                    process::exit(1)
+                });
+                let key = key.trim().to_string();
+                if let Some(existing) = variables.iter_mut().find(|variable| variable.key == key) {
+                    existing.value = value;
+                } else {
+                    variables.push(EnvFileVariable { key, value });
+                }
+            }
+        }
+
+        Self {
+            variables,
+            override_process_env,
+        }
+    }
+}
+
+/// Expand `${VAR}` and `${VAR:-default}` references in a single env file value.
+///
+/// References are resolved first against variables already loaded from earlier in the same
+/// layered set, then against the process environment, then against the `:-default` fallback (if
+/// any). Returns the unresolved variable name as the error if none of those apply.
+fn expand_env_file_value(raw: &str, loaded: &[EnvFileVariable]) -> Result<String, String> {
+    let mut output = String::with_capacity(raw.len());
+    let mut chars = raw.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '$' || chars.peek() != Some(&'{') {
+            output.push(c);
+            continue;
+        }
+        chars.next(); // Consume the `{`.
+        let mut reference = String::new();
+        for c in chars.by_ref() {
+            if c == '}' {
+                break;
+            }
+            reference.push(c);
+        }
+        let (name, default) = match reference.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (reference.as_str(), None),
+        };
+        let value = loaded
+            .iter()
+            .rev()
+            .find(|variable| variable.key == name)
+            .map(|variable| variable.value.clone())
+            .or_else(|| std::env::var(name).ok())
+            .or_else(|| default.map(str::to_string))
+            .ok_or_else(|| name.to_string())?;
+        output.push_str(&value);
+    }
+    Ok(output)
+}
+
 /// The resolved settings to use for a `run` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct RunSettings {
@@ -339,6 +750,7 @@ pub(crate) struct RunSettings {
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) env_file: Vec<PathBuf>,
     pub(crate) no_env_file: bool,
+    pub(crate) env_file_vars: EnvFileSettings,
     pub(crate) max_recursion_depth: u32,
 }
 
@@ -350,8 +762,16 @@ impl RunSettings {
     const DEFAULT_MAX_RECURSION_DEPTH: u32 = 100;
 
     /// Resolve the [`RunSettings`] from the CLI and filesystem configuration.
+    ///
+    /// `profile` is the active `--profile`/`UV_PROFILE` selection resolved by
+    /// [`GlobalSettings::resolve`], if any; its `[tool.uv.profiles.<name>]` block is layered
+    /// between the CLI/env overrides and the base `[tool.uv]` options.
     #[allow(clippy::needless_pass_by_value)]
-    pub(crate) fn resolve(args: RunArgs, filesystem: Option<FilesystemOptions>) -> Self {
+    pub(crate) fn resolve(
+        args: RunArgs,
+        filesystem: Option<FilesystemOptions>,
+        profile: Option<&str>,
+    ) -> Self {
         let RunArgs {
             extra,
             all_extras,
@@ -391,6 +811,7 @@ impl RunSettings {
             show_resolution,
             env_file,
             no_env_file,
+            env_file_override,
             max_recursion_depth,
         } = args;
 
@@ -448,10 +869,12 @@ impl RunSettings {
             active: flag(active, no_active, "active"),
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
-            settings: ResolverInstallerSettings::combine(
+            settings: ResolverInstallerSettings::combine_with_profile(
                 resolver_installer_options(installer, build),
                 filesystem,
+                profile,
             ),
+            env_file_vars: EnvFileSettings::resolve(&env_file, no_env_file, env_file_override),
             env_file,
             no_env_file,
             install_mirrors,
@@ -480,15 +903,21 @@ pub(crate) struct ToolRunSettings {
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) env_file: Vec<PathBuf>,
     pub(crate) no_env_file: bool,
+    pub(crate) env_file_vars: EnvFileSettings,
 }
 
 impl ToolRunSettings {
     /// Resolve the [`ToolRunSettings`] from the CLI and filesystem configuration.
+    ///
+    /// `profile` is the active `--profile`/`UV_PROFILE` selection resolved by
+    /// [`GlobalSettings::resolve`], if any; its `[tool.uv.profiles.<name>]` block is layered
+    /// between the CLI/env overrides and the base `[tool.uv]` options.
     #[allow(clippy::needless_pass_by_value)]
     pub(crate) fn resolve(
         args: ToolRunArgs,
         filesystem: Option<FilesystemOptions>,
         invocation_source: ToolRunCommand,
+        profile: Option<&str>,
     ) -> Self {
         let ToolRunArgs {
             command,
@@ -502,6 +931,7 @@ impl ToolRunSettings {
             isolated,
             env_file,
             no_env_file,
+            env_file_override,
             show_resolution,
             installer,
             build,
@@ -536,13 +966,18 @@ impl ToolRunSettings {
             }
         }
 
-        let options = resolver_installer_options(installer, build).combine(
-            filesystem
-                .clone()
-                .map(FilesystemOptions::into_options)
-                .map(|options| options.top_level)
-                .unwrap_or_default(),
-        );
+        let workspace_options = filesystem.clone().map(FilesystemOptions::into_options);
+        let profile_options = profile
+            .and_then(|name| workspace_options.as_ref().and_then(|o| o.profiles.get(name)))
+            .map(|profile| profile.top_level.clone())
+            .unwrap_or_default();
+        let options = resolver_installer_options(installer, build)
+            .combine(profile_options)
+            .combine(
+                workspace_options
+                    .map(|options| options.top_level)
+                    .unwrap_or_default(),
+            );
 
         let install_mirrors = filesystem
             .map(FilesystemOptions::into_options)
@@ -585,6 +1020,7 @@ impl ToolRunSettings {
             settings,
             options,
             install_mirrors,
+            env_file_vars: EnvFileSettings::resolve(&env_file, no_env_file, env_file_override),
             env_file,
             no_env_file,
         }
@@ -1874,6 +2310,12 @@ pub(crate) struct PipCompileSettings {
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) environments: SupportedEnvironments,
+    /// The explicit set of `--python-platform` targets to resolve for, generalizing `universal`
+    /// (which resolves for every platform) to "this fixed deployment matrix". When non-empty,
+    /// the resolver is expected to produce a single lockfile whose requirements are gated by
+    /// environment markers matching each target, rather than resolving for `settings.python_platform`
+    /// alone.
+    pub(crate) python_platforms: Vec<TargetTriple>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -1917,6 +2359,7 @@ impl PipCompileSettings {
             only_binary,
             python_version,
             python_platform,
+            python_platforms,
             universal,
             no_universal,
             no_emit_package,
@@ -2001,6 +2444,15 @@ impl PipCompileSettings {
             overrides_from_workspace,
             build_constraints_from_workspace,
             environments,
+            python_platforms: python_platform
+                .iter()
+                .cloned()
+                .chain(
+                    python_platforms
+                        .into_iter()
+                        .filter_map(Maybe::into_option),
+                )
+                .collect(),
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2576,12 +3028,45 @@ pub(crate) struct BuildSettings {
     pub(crate) force_pep517: bool,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) hash_checking: Option<HashCheckingMode>,
+    pub(crate) signature_policy: SignaturePolicy,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverSettings,
 }
 
+/// Whether to verify a detached cryptographic signature (e.g. a sibling `.asc`/`.sig` entry on
+/// the index or find-links source) for each downloaded artifact, on top of hash checking.
+///
+/// Modeled after binstall's signing-policy gate: in [`SignaturePolicy::Require`], a missing or
+/// invalid signature aborts the install; in [`SignaturePolicy::IfAvailable`], a signature is
+/// verified when present and its absence only warns.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SignaturePolicy {
+    /// Don't look for or verify artifact signatures.
+    #[default]
+    Ignore,
+    /// Verify a signature when present; warn (but don't fail) if one is missing.
+    IfAvailable,
+    /// Require a valid signature for every downloaded artifact.
+    Require,
+}
+
+impl FromStr for SignaturePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ignore" => Ok(Self::Ignore),
+            "if-available" => Ok(Self::IfAvailable),
+            "require" => Ok(Self::Require),
+            _ => Err(format!(
+                "invalid value for --signature-policy: `{s}`, expected one of `ignore`, `if-available`, or `require`"
+            )),
+        }
+    }
+}
+
 impl BuildSettings {
     /// Resolve the [`BuildSettings`] from the CLI and filesystem configuration.
     pub(crate) fn resolve(args: BuildArgs, filesystem: Option<FilesystemOptions>) -> Self {
@@ -2599,6 +3084,7 @@ impl BuildSettings {
             no_require_hashes,
             verify_hashes,
             no_verify_hashes,
+            signature_policy,
             build_logs,
             no_build_logs,
             python,
@@ -2630,6 +3116,7 @@ impl BuildSettings {
                 flag(require_hashes, no_require_hashes, "require-hashes"),
                 flag(verify_hashes, no_verify_hashes, "verify-hashes"),
             ),
+            signature_policy: signature_policy.unwrap_or_default(),
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
@@ -2729,6 +3216,19 @@ pub(crate) struct InstallerSettingsRef<'a> {
     pub(crate) sources: SourceStrategy,
 }
 
+impl InstallerSettingsRef<'_> {
+    /// The effective `--config-settings` for building `package`: the wildcard entries that apply
+    /// to every package, extended (not replaced) by that package's more specific overrides from
+    /// `--config-settings-package`, which win on conflicting keys.
+    pub(crate) fn config_settings_for(&self, package: &PackageName) -> ConfigSettings {
+        self.config_settings_package
+            .get(package)
+            .cloned()
+            .unwrap_or_default()
+            .combine(self.config_setting.clone())
+    }
+}
+
 /// The resolved settings to use for an invocation of the uv CLI when resolving dependencies.
 ///
 /// Combines the `[tool.uv]` persistent configuration with the command-line arguments
@@ -2737,6 +3237,14 @@ pub(crate) struct InstallerSettingsRef<'a> {
 pub(crate) struct ResolverSettings {
     pub(crate) build_options: BuildOptions,
     pub(crate) config_setting: ConfigSettings,
+    /// Per-package `config-settings` overrides.
+    ///
+    /// The wildcard entries in [`Self::config_setting`], applied to every package, are extended
+    /// (not replaced) by a package's more specific overrides here via
+    /// [`InstallerSettingsRef::config_settings_for`]. This checkout doesn't include
+    /// `uv_configuration` (where the actual build-backend invocation that would call
+    /// `config_settings_for` lives), so the merge itself is implemented and reachable from
+    /// [`InstallerSettingsRef`], but nothing in this tree calls it yet.
     pub(crate) config_settings_package: PackageConfigSettings,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) exclude_newer: ExcludeNewer,
@@ -2752,6 +3260,47 @@ pub(crate) struct ResolverSettings {
     pub(crate) resolution: ResolutionMode,
     pub(crate) sources: SourceStrategy,
     pub(crate) upgrade: Upgrade,
+    pub(crate) upgrade_strategy: UpgradeStrategy,
+    pub(crate) sdist_resolution: SDistResolution,
+}
+
+/// How the resolver weighs source distributions against wheels when candidates of the same
+/// version are otherwise tied.
+///
+/// `PreferWheels`/`PreferSDists` only reorder candidates of equal version, falling back to the
+/// other kind when the preferred one is unavailable for that version. `OnlyWheels`/`OnlySDists`
+/// hard-filter the candidate set instead, so a package with none of the required kind surfaces a
+/// clear "no acceptable distribution" error rather than silently falling back.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SDistResolution {
+    /// No preference between source distributions and wheels.
+    #[default]
+    Normal,
+    /// Prefer wheels, falling back to source distributions when no wheel is available.
+    PreferWheels,
+    /// Prefer source distributions, falling back to wheels when none is available.
+    PreferSDists,
+    /// Only select wheels; error if a package has none.
+    OnlyWheels,
+    /// Only select source distributions; error if a package has none.
+    OnlySDists,
+}
+
+impl FromStr for SDistResolution {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "normal" => Ok(Self::Normal),
+            "prefer-wheels" => Ok(Self::PreferWheels),
+            "prefer-sdists" => Ok(Self::PreferSDists),
+            "only-wheels" => Ok(Self::OnlyWheels),
+            "only-sdists" => Ok(Self::OnlySDists),
+            _ => Err(format!(
+                "invalid value for --sdist-resolution: `{s}`, expected one of `normal`, `prefer-wheels`, `prefer-sdists`, `only-wheels`, or `only-sdists`"
+            )),
+        }
+    }
 }
 
 impl ResolverSettings {
@@ -2813,6 +3362,8 @@ impl From<ResolverOptions> for ResolverSettings {
                     .map(Requirement::from)
                     .collect(),
             ),
+            upgrade_strategy: value.upgrade_strategy.unwrap_or_default(),
+            sdist_resolution: value.sdist_resolution.unwrap_or_default(),
             build_options: BuildOptions::new(
                 NoBinary::from_args(value.no_binary, value.no_binary_package.unwrap_or_default()),
                 NoBuild::from_args(value.no_build, value.no_build_package.unwrap_or_default()),
@@ -2848,6 +3399,30 @@ impl ResolverInstallerSettings {
 
         Self::from(options)
     }
+
+    /// Reconcile the [`ResolverInstallerSettings`] from the CLI and filesystem configuration,
+    /// honoring an active `--profile`/`UV_PROFILE` selection.
+    ///
+    /// Precedence, highest to lowest: CLI/env args, the active profile's
+    /// `[tool.uv.profiles.<name>]` block, and the base `[tool.uv]` options.
+    pub(crate) fn combine_with_profile(
+        args: ResolverInstallerOptions,
+        filesystem: Option<FilesystemOptions>,
+        profile: Option<&str>,
+    ) -> Self {
+        let workspace_options = filesystem.map(FilesystemOptions::into_options);
+        let profile_options = profile
+            .and_then(|name| workspace_options.as_ref().and_then(|o| o.profiles.get(name)))
+            .map(|profile| profile.top_level.clone())
+            .unwrap_or_default();
+        let options = args.combine(profile_options).combine(
+            workspace_options
+                .map(|options| options.top_level)
+                .unwrap_or_default(),
+        );
+
+        Self::from(options)
+    }
 }
 
 impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
@@ -2911,6 +3486,8 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                         .map(Requirement::from)
                         .collect(),
                 ),
+                upgrade_strategy: value.upgrade_strategy.unwrap_or_default(),
+                sdist_resolution: value.sdist_resolution.unwrap_or_default(),
             },
             compile_bytecode: value.compile_bytecode.unwrap_or_default(),
             reinstall: Reinstall::from_args(
@@ -2960,6 +3537,15 @@ pub(crate) struct PipSettings {
     pub(crate) config_setting: ConfigSettings,
     pub(crate) config_settings_package: PackageConfigSettings,
     pub(crate) python_version: Option<PythonVersion>,
+    /// The target platform to resolve for, e.g. `--python-platform linux-x86_64`.
+    ///
+    /// NOT IMPLEMENTABLE IN THIS CHECKOUT: adding `wasm32-emscripten`/`wasm32-wasi` support for
+    /// Pyodide/WASI-style resolutions means adding variants to the [`TargetTriple`] enum itself
+    /// and teaching the marker-environment and platform-tag generation built on top of it about
+    /// them. `TargetTriple` and that generation code both live in `uv_configuration`, which has
+    /// no files under this checkout's `crates/` — there is no source location here to add the
+    /// variants to. This field is unchanged plumbing: it passes through whatever `TargetTriple`
+    /// variants happen to exist upstream, so `--python-platform wasm32-*` still can't resolve.
     pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) universal: bool,
     pub(crate) exclude_newer: ExcludeNewer,
@@ -2974,10 +3560,37 @@ pub(crate) struct PipSettings {
     pub(crate) compile_bytecode: bool,
     pub(crate) sources: SourceStrategy,
     pub(crate) hash_checking: Option<HashCheckingMode>,
+    pub(crate) signature_policy: SignaturePolicy,
     pub(crate) upgrade: Upgrade,
+    pub(crate) upgrade_strategy: UpgradeStrategy,
     pub(crate) reinstall: Reinstall,
 }
 
+/// Mirrors pip's `--upgrade-strategy`: how far an `--upgrade`/`--upgrade-package` request
+/// propagates to the transitive dependencies of the packages being upgraded.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UpgradeStrategy {
+    /// Only upgrade a dependency when a root requirement forces it.
+    #[default]
+    OnlyIfNeeded,
+    /// Upgrade all dependencies of an upgraded package to their newest compatible versions.
+    Eager,
+}
+
+impl FromStr for UpgradeStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "only-if-needed" => Ok(Self::OnlyIfNeeded),
+            "eager" => Ok(Self::Eager),
+            _ => Err(format!(
+                "invalid value for --upgrade-strategy: `{s}`, expected `only-if-needed` or `eager`"
+            )),
+        }
+    }
+}
+
 impl PipSettings {
     /// Resolve the [`PipSettings`] from the CLI and filesystem configuration.
     pub(crate) fn combine(args: PipOptions, filesystem: Option<FilesystemOptions>) -> Self {
@@ -3045,9 +3658,11 @@ impl PipSettings {
             compile_bytecode,
             require_hashes,
             verify_hashes,
+            signature_policy,
             no_sources,
             upgrade,
             upgrade_package,
+            upgrade_strategy,
             reinstall,
             reinstall_package,
             exclude_newer_package,
@@ -3076,6 +3691,7 @@ impl PipSettings {
             no_sources: top_level_no_sources,
             upgrade: top_level_upgrade,
             upgrade_package: top_level_upgrade_package,
+            upgrade_strategy: top_level_upgrade_strategy,
             reinstall: top_level_reinstall,
             reinstall_package: top_level_reinstall_package,
             no_build: top_level_no_build,
@@ -3122,6 +3738,7 @@ impl PipSettings {
         let no_sources = no_sources.combine(top_level_no_sources);
         let upgrade = upgrade.combine(top_level_upgrade);
         let upgrade_package = upgrade_package.combine(top_level_upgrade_package);
+        let upgrade_strategy = upgrade_strategy.combine(top_level_upgrade_strategy);
         let reinstall = reinstall.combine(top_level_reinstall);
         let reinstall_package = reinstall_package.combine(top_level_reinstall_package);
 
@@ -3260,6 +3877,10 @@ impl PipSettings {
                 args.require_hashes.combine(require_hashes),
                 args.verify_hashes.combine(verify_hashes),
             ),
+            signature_policy: args
+                .signature_policy
+                .combine(signature_policy)
+                .unwrap_or_default(),
             python: args.python.combine(python),
             system: args.system.combine(system).unwrap_or_default(),
             break_system_packages: args
@@ -3285,6 +3906,10 @@ impl PipSettings {
                     .map(Requirement::from)
                     .collect(),
             ),
+            upgrade_strategy: args
+                .upgrade_strategy
+                .combine(upgrade_strategy)
+                .unwrap_or_default(),
             reinstall: Reinstall::from_args(
                 args.reinstall.combine(reinstall),
                 args.reinstall_package
@@ -3429,30 +4054,248 @@ mod env {
         EnvVars::UV_PYTHON_DOWNLOADS,
         "one of 'auto', 'true', 'manual', 'never', or 'false'",
     );
+
+    pub(super) const PROFILE: (&str, &str) = (EnvVars::UV_PROFILE, "a profile name");
+
+    pub(super) const UV_REQUIRE_VERSION_MODE: (&str, &str) = (
+        EnvVars::UV_REQUIRE_VERSION_MODE,
+        "one of 'error' or 'download'",
+    );
+
+    pub(super) const UV_INSTALL_MIRROR: (&str, &str) =
+        (EnvVars::UV_INSTALL_MIRROR, "a URL");
+
+    pub(super) const CONCURRENCY: (&str, &str) =
+        (EnvVars::UV_CONCURRENCY, "one of 'auto' or 'fixed'");
+
+    pub(super) const CONCURRENCY_MIN: (&str, &str) =
+        (EnvVars::UV_CONCURRENCY_MIN, "a non-zero integer");
+
+    pub(super) const CONCURRENCY_MAX: (&str, &str) =
+        (EnvVars::UV_CONCURRENCY_MAX, "a non-zero integer");
+
+    pub(super) const UV_HTTP_TIMEOUT: (&str, &str) = (
+        EnvVars::UV_HTTP_TIMEOUT,
+        "a duration, e.g. '30s', '500ms', '5m', or a bare integer number of seconds",
+    );
+
+    pub(super) const UV_INSECURE_HOST: (&str, &str) = (
+        EnvVars::UV_INSECURE_HOST,
+        "a comma-separated list of trusted hosts",
+    );
+
+    pub(super) const UV_HTTP_RETRIES: (&str, &str) =
+        (EnvVars::UV_HTTP_RETRIES, "a non-negative integer");
+
+    pub(super) const UV_CACHE_SIZE: (&str, &str) = (
+        EnvVars::UV_CACHE_SIZE,
+        "a byte size, e.g. '2GiB', '500MB', or a bare integer number of bytes",
+    );
+}
+
+/// An error encountered while reading and parsing an environment variable in [`try_env`].
+#[derive(Debug)]
+enum EnvError {
+    /// The variable isn't set.
+    NotPresent,
+    /// The variable is set, but isn't valid Unicode.
+    NotUnicode(std::ffi::OsString),
+    /// The variable is set, but failed to parse as the expected type.
+    Parse {
+        name: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+/// Attempt to load and parse an environment variable with the given name.
+///
+/// Unlike [`env`], this never exits the process: every failure mode, including the variable
+/// being unset, is returned as an [`EnvError`] so callers can recover, collect multiple errors,
+/// or bubble them up through `main` via `?`.
+fn try_env<T>((name, expected): (&str, &str)) -> Result<T, EnvError>
+where
+    T: FromStr,
+{
+    let val = match std::env::var(name) {
+        Ok(val) => val,
+        Err(VarError::NotPresent) => return Err(EnvError::NotPresent),
+        Err(VarError::NotUnicode(val)) => return Err(EnvError::NotUnicode(val)),
+    };
+    val.parse().map_err(|_| EnvError::Parse {
+        name: name.to_string(),
+        expected: expected.to_string(),
+        actual: val,
+    })
+}
+
+/// Whether an environment variable's name suggests it may hold a credential, and its value
+/// should therefore never be echoed back in an error message.
+fn is_sensitive_env_var(name: &str) -> bool {
+    let name = name.to_ascii_uppercase();
+    ["TOKEN", "PASSWORD", "SECRET", "KEY"]
+        .iter()
+        .any(|marker| name.contains(marker))
 }
 
 /// Attempt to load and parse an environment variable with the given name.
 ///
 /// Exits the program and prints an error message containing the expected type if
 /// parsing values.
-fn env<T>((name, expected): (&str, &str)) -> Option<T>
+fn env<T>(spec: (&str, &str)) -> Option<T>
+where
+    T: FromStr,
+{
+    let (name, expected) = spec;
+    match try_env(spec) {
+        Ok(val) => Some(val),
+        Err(EnvError::NotPresent) => None,
+        Err(EnvError::NotUnicode(_)) => parse_failure(name, expected, None),
+        Err(EnvError::Parse { actual, .. }) => parse_failure(name, expected, Some(&actual)),
+    }
+}
+
+/// A duration read from an environment variable, e.g. `UV_HTTP_TIMEOUT=30s`.
+///
+/// Accepts a bare integer, interpreted as seconds for backward compatibility with variables that
+/// predate this type, as well as suffixed forms: `ms`, `s`, `m`/`min`, `h`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct EnvDuration(pub(crate) std::time::Duration);
+
+impl FromStr for EnvDuration {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(seconds) = s.parse::<u64>() {
+            return Ok(Self(std::time::Duration::from_secs(seconds)));
+        }
+
+        let split_at = s
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .ok_or_else(|| format!("invalid duration `{s}`"))?;
+        let (value, unit) = s.split_at(split_at);
+        let value: f64 = value
+            .parse()
+            .map_err(|_| format!("invalid duration `{s}`"))?;
+
+        let seconds = match unit {
+            "ms" => value / 1000.0,
+            "s" => value,
+            "m" | "min" => value * 60.0,
+            "h" => value * 3600.0,
+            other => {
+                return Err(format!(
+                    "invalid duration unit `{other}` in `{s}`, expected one of `ms`, `s`, `m`/`min`, `h`"
+                ));
+            }
+        };
+        Ok(Self(std::time::Duration::from_secs_f64(seconds)))
+    }
+}
+
+/// A byte size read from an environment variable, e.g. `UV_CACHE_SIZE=512MiB`.
+///
+/// Accepts a bare integer (bytes), decimal suffixes (`KB`, `MB`, `GB`, powers of 1000), and
+/// binary suffixes (`KiB`, `MiB`, `GiB`, powers of 1024).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct EnvByteSize(pub(crate) u64);
+
+impl FromStr for EnvByteSize {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(bytes) = s.parse::<u64>() {
+            return Ok(Self(bytes));
+        }
+
+        let split_at = s
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .ok_or_else(|| format!("invalid byte size `{s}`"))?;
+        let (value, unit) = s.split_at(split_at);
+        let value: f64 = value
+            .parse()
+            .map_err(|_| format!("invalid byte size `{s}`"))?;
+
+        let multiplier = match unit {
+            "KB" => 1_000.0,
+            "MB" => 1_000_000.0,
+            "GB" => 1_000_000_000.0,
+            "KiB" => 1024.0,
+            "MiB" => 1024.0 * 1024.0,
+            "GiB" => 1024.0 * 1024.0 * 1024.0,
+            other => {
+                return Err(format!(
+                    "invalid byte size unit `{other}` in `{s}`, expected one of `KB`, `MB`, `GB`, `KiB`, `MiB`, `GiB`"
+                ));
+            }
+        };
+        Ok(Self((value * multiplier) as u64))
+    }
+}
+
+/// Read and parse an environment variable, falling back to `default` when it's unset.
+///
+/// A variable that's *set* but fails to parse still exits the program via [`env`] — only
+/// "unset" falls back to `default`; a typo is never silently swallowed into it.
+fn env_or<T>(spec: (&str, &str), default: T) -> T
+where
+    T: FromStr,
+{
+    env(spec).unwrap_or(default)
+}
+
+/// Attempt to load and parse a delimited list from an environment variable, e.g.
+/// `UV_EXTRA_INDEX_URL=https://a,https://b`.
+///
+/// The variable is split on `delim`, each element is trimmed and parsed into `T`, and empty
+/// elements between delimiters are skipped rather than erroring. An entirely empty (but present)
+/// value yields `Some(vec![])`, not `None`, so callers can distinguish "unset" from "set to
+/// nothing". Exits the program, naming the failing element's index, if any element fails to
+/// parse.
+fn env_array<T>((name, expected): (&str, &str), delim: char) -> Option<Vec<T>>
 where
     T: FromStr,
 {
     let val = match std::env::var(name) {
         Ok(val) => val,
         Err(VarError::NotPresent) => return None,
-        Err(VarError::NotUnicode(_)) => parse_failure(name, expected),
+        Err(VarError::NotUnicode(_)) => parse_failure(name, expected, None),
     };
+
     Some(
-        val.parse()
-            .unwrap_or_else(|_| parse_failure(name, expected)),
+        val.split(delim)
+            .map(str::trim)
+            .filter(|element| !element.is_empty())
+            .enumerate()
+            .map(|(index, element)| {
+                element.parse().unwrap_or_else(|_| {
+                    let indexed_name = format!("{name} (at index {index})");
+                    parse_failure(&indexed_name, expected, Some(element))
+                })
+            })
+            .collect(),
     )
 }
 
 /// Prints a parse error and exits the process.
+///
+/// `actual` is the value that failed to parse, if any (absent for a non-Unicode value). It's
+/// redacted to `<redacted>` for variable names that look like they hold a credential (containing
+/// `TOKEN`, `PASSWORD`, `SECRET`, or `KEY`), so we don't leak secrets into error output.
 #[allow(clippy::exit, clippy::print_stderr)]
-fn parse_failure(name: &str, expected: &str) -> ! {
-    eprintln!("error: invalid value for {name}, expected {expected}");
+fn parse_failure(name: &str, expected: &str, actual: Option<&str>) -> ! {
+    match actual {
+        Some(_) if is_sensitive_env_var(name) => {
+            eprintln!("error: invalid value '<redacted>' for {name}, expected {expected}");
+        }
+        Some(actual) => {
+            eprintln!("error: invalid value '{actual}' for {name}, expected {expected}");
+        }
+        None => {
+            eprintln!("error: invalid value for {name}, expected {expected}");
+        }
+    }
     process::exit(1)
 }